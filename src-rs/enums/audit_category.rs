@@ -0,0 +1,14 @@
+use crate::enum_str;
+
+enum_str! {
+    enum AuditCategory {
+        Import_Error,
+        Duplicate_Car_Number,
+        No_Runs,
+        Timed_DNS,
+        Excessive_Cones,
+        PAX_Mismatch,
+        Novice_In_Pro,
+        Co_Driver,
+    }
+}
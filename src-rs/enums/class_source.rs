@@ -0,0 +1,8 @@
+use crate::enum_str;
+
+enum_str! {
+    enum ClassSource {
+        Pronto,
+        MSR,
+    }
+}
@@ -0,0 +1,12 @@
+use crate::enum_str;
+
+enum_str! {
+    enum MembershipStatus {
+        Member,
+        Weekend,
+        Expired,
+        Not_On_Roster,
+        Invalid_Number,
+        No_Number,
+    }
+}
@@ -1,5 +1,10 @@
+pub mod audit_category;
+pub mod audit_severity;
 pub mod championship_type;
 pub mod class_category;
+pub mod class_source;
 pub mod driver_group;
 pub mod long_car_class;
+pub mod membership_status;
 pub mod short_car_class;
+pub mod visitor_policy;
@@ -0,0 +1,8 @@
+use crate::enum_str;
+
+enum_str! {
+    enum VisitorPolicy {
+        Exclude,
+        Flag,
+    }
+}
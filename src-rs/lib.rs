@@ -4,13 +4,38 @@ use wasm_bindgen::prelude::wasm_bindgen;
 use wasm_bindgen::JsValue;
 
 use enums::championship_type::ChampionshipType;
+use enums::class_source::ClassSource;
 use enums::driver_group::DriverGroup;
 
+use crate::enums::visitor_policy::VisitorPolicy;
+use crate::models::driver::Driver;
+use crate::models::event::Event;
 use crate::models::event_results::EventResults;
+use crate::models::membership::MembershipRules;
+use crate::models::novice_graduation::GraduationRules;
+use crate::models::organization_rules::OrganizationRules;
+use crate::models::region_policy::RegionPolicy;
+use crate::models::scenario::HypotheticalResult;
+use crate::models::series_definition::SeriesDefinition;
+use crate::services::calculators::clinch_calculator::DefaultClinchCalculator;
+use crate::services::calculators::rating_calculator::{rating_state, DefaultRatingCalculator, RatingCalculator};
+use crate::services::calculators::scenario_calculator::DefaultScenarioCalculator;
 use crate::services::csv::builder::event::class_results_builder::ClassResultsBuilder;
 use crate::services::csv::builder::event::combined_results_builder::CombinedResultsBuilder;
+use crate::services::csv::builder::event::full_results_builder::FullResultsBuilder;
+use crate::services::csv::builder::event::series_submission_builder::SeriesSubmissionBuilder;
 use crate::services::csv::parser::championship_results_parser::ChampionshipResultsParser;
-use crate::services::csv::parser::event_results_parser::parse;
+use crate::services::csv::parser::event_results_parser::{parse, parse_msr};
+use crate::services::html::event_results_page::EventResultsPage;
+use crate::services::html::full_results_page::FullResultsPage;
+use crate::services::html::standings_page::StandingsPage;
+use crate::services::pdf::event_results_pdf::EventResultsPdf;
+use crate::services::reports::consistency_report_builder::ConsistencyReportBuilder;
+use crate::services::reports::{event_label, parse_season};
+use crate::services::tools::home_region::mark_visitors;
+use crate::services::tools::membership::{check_memberships, parse_roster};
+use crate::services::tools::novice_graduation::{exclude_graduates, mark_graduates, NoviceTracker};
+use crate::services::tools::region_backfill::backfill_regions;
 
 pub mod enums;
 mod models;
@@ -23,21 +48,61 @@ pub struct SccaSoloPointsEngine {
     champ_parser: ChampionshipResultsParser,
     class_results_builder: ClassResultsBuilder,
     combined_results_builder: CombinedResultsBuilder,
+    full_results_builder: FullResultsBuilder,
+    series_submission_builder: SeriesSubmissionBuilder,
+    full_results_page: FullResultsPage,
+    event_results_page: EventResultsPage,
+    standings_page: StandingsPage,
+    event_results_pdf: EventResultsPdf,
+    consistency_report_builder: ConsistencyReportBuilder,
+    rating_calculator: DefaultRatingCalculator,
+    novice_tracker: NoviceTracker,
+    region_policy: Option<RegionPolicy>,
+    membership_rules: Option<MembershipRules>,
+    /// Leave graduates still entered as novices out of the Novice championship
+    exclude_graduates: bool,
+    /// Co-hosting organizations scoring their own championships from this event
+    organizations: Vec<(OrganizationRules, ChampionshipResultsParser)>,
 }
 
 /// Main entry point, serving as an interface for the disparate methods and functions needed by the
 /// JS engine from the WASM engine
 #[wasm_bindgen]
 impl SccaSoloPointsEngine {
+    /// `class_source` picks which export wins when Pronto and MSR disagree on a driver's class, defaulting to Pronto.
+    /// `event` describes the event in every output and labels its column in championship standings.
     #[wasm_bindgen(constructor)]
-    pub fn new(msr_export: String, pronto_export: String) -> Result<SccaSoloPointsEngine, String> {
-        let event_results = parse(msr_export, pronto_export)?;
+    pub fn new(
+        msr_export: Option<String>,
+        pronto_export: String,
+        class_source: Option<ClassSource>,
+        event: Option<Event>,
+    ) -> Result<SccaSoloPointsEngine, String> {
+        let event_results = parse(
+            msr_export,
+            pronto_export,
+            class_source.unwrap_or(ClassSource::Pronto),
+            event.unwrap_or_default(),
+        )?;
         let champ_parser = ChampionshipResultsParser::new(event_results.clone());
         Ok(SccaSoloPointsEngine {
             event_results,
             champ_parser,
             class_results_builder: Default::default(),
             combined_results_builder: Default::default(),
+            full_results_builder: Default::default(),
+            series_submission_builder: Default::default(),
+            full_results_page: Default::default(),
+            event_results_page: Default::default(),
+            standings_page: Default::default(),
+            event_results_pdf: Default::default(),
+            consistency_report_builder: Default::default(),
+            rating_calculator: Default::default(),
+            novice_tracker: Default::default(),
+            region_policy: None,
+            membership_rules: None,
+            exclude_graduates: false,
+            organizations: vec![],
         })
     }
 
@@ -46,6 +111,21 @@ impl SccaSoloPointsEngine {
         self.event_results.js_drivers_in_error()
     }
 
+    /// See [`crate::models::event_results::EventResults::js_no_shows()`]
+    pub fn js_no_shows(&self) -> Vec<JsValue> {
+        self.event_results.js_no_shows()
+    }
+
+    /// See [`crate::models::event_results::EventResults::audit()`]
+    pub fn js_audit(&self) -> Vec<JsValue> {
+        self.event_results.js_audit()
+    }
+
+    /// See [`crate::models::event_results::EventResults::js_source_discrepancies()`]
+    pub fn js_source_discrepancies(&self) -> Vec<JsValue> {
+        self.event_results.js_source_discrepancies()
+    }
+
     pub fn get_header_for_event_class_results(&self) -> String {
         self.class_results_builder.get_header()
     }
@@ -54,11 +134,236 @@ impl SccaSoloPointsEngine {
         self.class_results_builder.to_csvs(&self.event_results)
     }
 
+    /// Every class's results in one CSV, below the event's details. See
+    /// [`crate::services::csv::builder::event::class_results_builder::ClassResultsBuilder::to_csv()`]
+    pub fn get_event_class_results_csv(&self) -> Result<String, String> {
+        self.class_results_builder.to_csv(&self.event_results)
+    }
+
+    /// Typed counterpart of [`SccaSoloPointsEngine::get_event_class_results_csvs()`]
+    pub fn get_event_class_results(&self) -> Vec<JsValue> {
+        self.class_results_builder.to_rows(&self.event_results)
+    }
+
+    /// Typed counterpart of [`SccaSoloPointsEngine::get_event_combined_csv()`]
+    pub fn get_event_combined_results(&self, driver_group: DriverGroup) -> Result<JsValue, String> {
+        let rows = self
+            .combined_results_builder
+            .to_combined_rows(&self.event_results, driver_group)?;
+        serde_wasm_bindgen::to_value(&rows).map_err(|e| e.to_string())
+    }
+
     pub fn get_event_combined_csv(&self, driver_group: DriverGroup) -> Result<String, String> {
         self.combined_results_builder
             .to_combined_csv(&self.event_results, driver_group)
     }
 
+    /// Finishers in a divisional or national series' classes, with their series points
+    pub fn get_series_submission(&self, series: SeriesDefinition) -> Result<JsValue, String> {
+        let rows = self
+            .series_submission_builder
+            .build_rows(&self.event_results, &series)?;
+        serde_wasm_bindgen::to_value(&rows).map_err(|e| e.to_string())
+    }
+
+    /// The series' submission CSV. See [`Self::get_series_submission()`]
+    pub fn get_series_submission_csv(&self, series: SeriesDefinition) -> Result<String, String> {
+        self.series_submission_builder.to_csv(&self.event_results, &series)
+    }
+
+    /// Every run of every driver, grouped by class
+    pub fn get_event_full_results_csv(&self) -> Result<String, String> {
+        self.full_results_builder.to_csv(&self.event_results)
+    }
+
+    /// Every run of every driver, grouped by class, as a self-contained HTML page
+    pub fn get_event_full_results_html(&self) -> String {
+        self.full_results_page.render(&self.event_results)
+    }
+
+    /// Class results and overall PAX, Raw, Novice and Ladies results as a self-contained HTML page
+    pub fn get_event_results_html(&self) -> Result<String, String> {
+        self.event_results_page.render(&self.event_results)
+    }
+
+    /// Printable class results and overall PAX and Raw lists, one class per page
+    pub fn get_event_results_pdf(&self) -> Result<Vec<u8>, String> {
+        self.event_results_pdf.render(&self.event_results)
+    }
+
+    /// Run-by-run consistency of every driver and class, for coaching and the novice program
+    pub fn get_consistency_report(&self) -> Result<JsValue, String> {
+        serde_wasm_bindgen::to_value(&self.consistency_report_builder.build(&self.event_results))
+            .map_err(|e| e.to_string())
+    }
+
+    /// Same as [`SccaSoloPointsEngine::get_consistency_report()`], as a driver table followed by a class table
+    pub fn get_consistency_report_csv(&self) -> String {
+        self.consistency_report_builder.build(&self.event_results).to_csv()
+    }
+
+    /// Add this event to driver ratings kept from previous events, or start new ratings when `state` is empty. The
+    /// returned state should be kept for the next event.
+    pub fn update_ratings(&self, state: JsValue) -> Result<JsValue, String> {
+        let mut state = rating_state(state)?;
+        let event = event_label(&self.event_results, state.events_rated);
+        self.rating_calculator.rate(&mut state, &self.event_results, event);
+        serde_wasm_bindgen::to_value(&state).map_err(|e| e.to_string())
+    }
+
+    /// Same as [`SccaSoloPointsEngine::add_prior_championship_results()`], but rendered as a self-contained HTML page
+    pub fn get_championship_html(
+        &self,
+        new_results_type: ChampionshipType,
+        new_results: &[u8],
+        file_name: String,
+    ) -> Result<String, JsValue> {
+        self.champ_parser
+            .process_standings(new_results_type, new_results, file_name)
+            .map(|standings| match standings {
+                Some(standings) => self.standings_page.render(&self.event_results.event, &standings),
+                None => format!("No results for {}", new_results_type.name()),
+            })
+            .map_err(|e| JsValue::from_str(e.as_str()))
+    }
+
+    /// Typed counterpart of [`SccaSoloPointsEngine::add_prior_championship_results()`]. Resolves to `null` if nobody
+    /// has scored.
+    pub fn get_championship_standings(
+        &self,
+        new_results_type: ChampionshipType,
+        new_results: &[u8],
+        file_name: String,
+    ) -> Result<JsValue, JsValue> {
+        let standings = self
+            .champ_parser
+            .process_standings(new_results_type, new_results, file_name)
+            .map_err(|e| JsValue::from_str(e.as_str()))?;
+        serde_wasm_bindgen::to_value(&standings).map_err(JsValue::from)
+    }
+
+    /// Who has clinched, who is eliminated and what everyone still needs, for each class or for the indexed
+    /// championship, given the number of events left in the season after this one
+    pub fn get_championship_outlook(
+        &self,
+        new_results_type: ChampionshipType,
+        new_results: &[u8],
+        file_name: String,
+        remaining_events: usize,
+    ) -> Result<JsValue, JsValue> {
+        let outlook = self
+            .champ_parser
+            .process_standings(new_results_type, new_results, file_name)
+            .map_err(|e| JsValue::from_str(e.as_str()))?
+            .map(|standings| standings.outlook(&DefaultClinchCalculator {}, remaining_events))
+            .unwrap_or_default();
+        serde_wasm_bindgen::to_value(&outlook).map_err(JsValue::from)
+    }
+
+    /// Projected standings if the hypothetical results (a list of `{ driver, car_class, points }`, one entry of
+    /// `points` per remaining event) came true. Drivers without a hypothetical result score zero.
+    pub fn get_championship_scenario(
+        &self,
+        new_results_type: ChampionshipType,
+        new_results: &[u8],
+        file_name: String,
+        hypothetical: JsValue,
+    ) -> Result<JsValue, JsValue> {
+        let hypothetical = serde_wasm_bindgen::from_value::<Vec<HypotheticalResult>>(hypothetical)?;
+        let projected = self
+            .champ_parser
+            .process_standings(new_results_type, new_results, file_name)
+            .map_err(|e| JsValue::from_str(e.as_str()))?
+            .map(|standings| standings.project(&DefaultScenarioCalculator::default(), &hypothetical))
+            .unwrap_or_default();
+        serde_wasm_bindgen::to_value(&projected).map_err(JsValue::from)
+    }
+
+    /// Fill in missing regions of record from an MSR export, returning the number of drivers updated.
+    /// See [`crate::services::tools::region_backfill::backfill_regions()`]
+    pub fn backfill_regions(&mut self, msr_export: String) -> Result<usize, String> {
+        let updated = backfill_regions(&mut self.event_results, &parse_msr(msr_export)?);
+        self.rebuild_championships();
+        Ok(updated)
+    }
+
+    /// Novice eligibility of every driver entered as a novice this season, given the Pronto exports of earlier events in
+    /// season order and, optionally, their details. When the rules say so, graduates still entered as novices are taken
+    /// out of the Novice championship for this event.
+    pub fn track_novices(
+        &mut self,
+        prior_pronto_exports: Vec<String>,
+        prior_events: Vec<Event>,
+        rules: GraduationRules,
+    ) -> Result<JsValue, String> {
+        let statuses = self.novice_tracker.track(
+            &parse_season(prior_pronto_exports, prior_events)?,
+            &self.event_results,
+            &rules,
+        )?;
+        mark_graduates(&mut self.event_results, &statuses);
+        self.exclude_graduates = rules.exclude_graduates;
+        self.rebuild_championships();
+        serde_wasm_bindgen::to_value(&statuses).map_err(|e| e.to_string())
+    }
+
+    /// Limit championship scoring to the home regions, returning the number of visiting drivers. Visitors keep their
+    /// event results either way.
+    pub fn set_region_policy(&mut self, policy: RegionPolicy) -> usize {
+        self.region_policy = Some(policy);
+        self.rebuild_championships()
+    }
+
+    /// Membership of every driver at the event, checked against a local roster export as of the event date. When the
+    /// rules say so, drivers who are not eligible members are taken out of championship scoring.
+    pub fn check_memberships(&mut self, roster_export: String, rules: MembershipRules) -> Result<JsValue, String> {
+        let as_of = self.event_results.event.date.clone();
+        let checks = check_memberships(
+            &mut self.event_results,
+            &parse_roster(roster_export)?,
+            as_of.as_deref(),
+            &rules,
+        )?;
+        self.membership_rules = Some(rules);
+        self.rebuild_championships();
+        serde_wasm_bindgen::to_value(&checks).map_err(|e| e.to_string())
+    }
+
+    /// Score a co-hosting organization's own championships from this event, under its own rules, returning the number
+    /// of its drivers at the event. Adding an organization again replaces its rules.
+    pub fn add_organization(&mut self, rules: OrganizationRules) -> usize {
+        let drivers = self
+            .event_results
+            .get_drivers(None)
+            .iter()
+            .filter(|d| rules.scores(d))
+            .count();
+        let parser = self.organization_parser(&rules);
+        self.organizations
+            .retain(|(existing, _)| existing.organization != rules.organization);
+        self.organizations.push((rules, parser));
+        drivers
+    }
+
+    /// Like [`Self::add_prior_championship_results()`], for one of the organizations added with
+    /// [`Self::add_organization()`] and its own prior standings
+    pub fn add_organization_championship_results(
+        &self,
+        organization: String,
+        new_results_type: ChampionshipType,
+        new_results: &[u8],
+        file_name: String,
+    ) -> Result<String, JsValue> {
+        let (_, parser) = self
+            .organizations
+            .iter()
+            .find(|(rules, _)| rules.organization == organization)
+            .ok_or_else(|| JsValue::from_str(&format!("No rules have been added for {organization}")))?;
+        parser
+            .process_results(new_results_type, new_results, file_name)
+            .map_err(|e| JsValue::from_str(e.as_str()))
+    }
+
     /// See [`crate::services::championship_results_parser::ChampionshipResultsParser::process_results()`]
     pub fn add_prior_championship_results(
         &self,
@@ -71,3 +376,47 @@ impl SccaSoloPointsEngine {
             .map_err(|e| JsValue::from_str(e.as_str()))
     }
 }
+
+impl SccaSoloPointsEngine {
+    /// Start championship scoring over, including each co-hosting organization's, after the event results change.
+    /// Visitors are left out if the region policy says so. Returns the number of visitors.
+    fn rebuild_championships(&mut self) -> usize {
+        let visitors = match &self.region_policy {
+            Some(policy) => mark_visitors(&mut self.event_results, policy),
+            None => 0,
+        };
+        let exclude_visitors = self
+            .region_policy
+            .as_ref()
+            .is_some_and(|policy| policy.visitors == VisitorPolicy::Exclude);
+        self.champ_parser =
+            ChampionshipResultsParser::new(self.championship_results(|d| exclude_visitors && d.visitor));
+        self.organizations = std::mem::take(&mut self.organizations)
+            .into_iter()
+            .map(|(rules, _)| {
+                let parser = self.organization_parser(&rules);
+                (rules, parser)
+            })
+            .collect();
+        visitors
+    }
+
+    /// Championship scoring limited to an organization's own drivers
+    fn organization_parser(&self, rules: &OrganizationRules) -> ChampionshipResultsParser {
+        ChampionshipResultsParser::from(self.championship_results(|d| !rules.scores(d)), rules)
+    }
+
+    /// The copy of the event results that championships are scored from, without the `excluded` drivers. Non-members
+    /// and novice graduates are also left out if the membership and graduation rules say so; the event's own results
+    /// keep everyone.
+    fn championship_results(&self, excluded: impl Fn(&Driver) -> bool) -> EventResults {
+        let exclude_non_members = self.membership_rules.is_some_and(|rules| rules.exclude_non_members);
+        let mut results = self
+            .event_results
+            .without(|d| excluded(d) || (exclude_non_members && d.non_member));
+        if self.exclude_graduates {
+            exclude_graduates(&mut results);
+        }
+        results
+    }
+}
@@ -0,0 +1,33 @@
+use serde::Serialize;
+
+use crate::enums::audit_category::AuditCategory;
+use crate::enums::audit_severity::AuditSeverity;
+use crate::enums::short_car_class::ShortCarClass;
+use crate::models::driver::Driver;
+use crate::models::type_aliases::DriverId;
+
+/// A problem found in event results that should be resolved before they are published
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct AuditFinding {
+    pub severity: AuditSeverity,
+    pub category: AuditCategory,
+    pub driver_id: DriverId,
+    pub driver_name: String,
+    pub car_number: u16,
+    pub car_class: ShortCarClass,
+    pub message: String,
+}
+
+impl AuditFinding {
+    pub fn new(severity: AuditSeverity, category: AuditCategory, driver: &Driver, message: String) -> AuditFinding {
+        AuditFinding {
+            severity,
+            category,
+            driver_id: driver.id.clone(),
+            driver_name: driver.name.clone(),
+            car_number: driver.car_number,
+            car_class: driver.car_class.short,
+            message,
+        }
+    }
+}
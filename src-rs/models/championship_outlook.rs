@@ -0,0 +1,29 @@
+use serde::Serialize;
+
+use crate::enums::championship_type::ChampionshipType;
+use crate::enums::short_car_class::ShortCarClass;
+
+/// What is still mathematically possible in one championship, or in one class of the class championship
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ChampionshipOutlook {
+    pub championship_type: ChampionshipType,
+    pub car_class: Option<ShortCarClass>,
+    pub remaining_events: usize,
+    /// Ordered by current standing
+    pub drivers: Vec<ClinchStatus>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ClinchStatus {
+    pub driver: String,
+    /// Counting points today, using today's drop rule
+    pub current_total: i64,
+    /// Counting points at the end of the season if the driver scores nothing more
+    pub guaranteed_total: i64,
+    /// Counting points at the end of the season if the driver wins every remaining event
+    pub max_possible_total: i64,
+    pub clinched: bool,
+    pub eliminated: bool,
+    /// Counting points the driver still needs to clinch if every rival wins out, or `None` once eliminated
+    pub magic_number: Option<i64>,
+}
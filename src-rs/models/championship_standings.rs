@@ -0,0 +1,215 @@
+use serde::Serialize;
+
+use crate::enums::championship_type::ChampionshipType;
+use crate::enums::short_car_class::ShortCarClass;
+use crate::models::championship_driver::ChampionshipDriver;
+use crate::models::championship_outlook::ChampionshipOutlook;
+use crate::models::scenario::{HypotheticalResult, ProjectedStandings};
+use crate::services::calculators::clinch_calculator::ClinchCalculator;
+use crate::services::calculators::scenario_calculator::ScenarioCalculator;
+
+/// Season standings for one championship, after the latest event has been added
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ChampionshipStandings {
+    pub championship_type: ChampionshipType,
+    pub organization: String,
+    pub year: u16,
+    pub event_count: usize,
+    pub events_to_count: usize,
+    /// Column label for each event, oldest first
+    pub event_labels: Vec<String>,
+    /// One section per class for the class championship, or a single unlabelled section for indexed championships
+    pub sections: Vec<ChampionshipSection>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ChampionshipSection {
+    pub car_class: Option<ShortCarClass>,
+    pub label: Option<String>,
+    pub rows: Vec<ChampionshipRow>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ChampionshipRow {
+    pub trophy: bool,
+    pub rank: usize,
+    pub driver: String,
+    pub points: Vec<i64>,
+    pub total_points: i64,
+    pub best_of: i64,
+    /// Scored while visiting from outside the home regions. Not part of the CSV, which is re-imported next event.
+    pub visitor: bool,
+}
+
+impl ChampionshipStandings {
+    /// Generic `Event #N` labels, used until an event is given a better one
+    pub fn default_event_labels(event_count: usize) -> Vec<String> {
+        (0..event_count).map(|i| format!("Event #{}", i + 1)).collect()
+    }
+
+    /// Keep the labels of events carried over from the prior standings
+    pub fn label_past_events(&mut self, labels: &[String]) {
+        self.event_labels
+            .iter_mut()
+            .zip(labels.iter())
+            .for_each(|(label, past)| *label = past.clone());
+    }
+
+    /// Mark the rows of the named drivers as visitors
+    pub fn flag_visitors(&mut self, names: &[String]) {
+        self.sections
+            .iter_mut()
+            .flat_map(|section| section.rows.iter_mut())
+            .for_each(|row| row.visitor = names.iter().any(|name| name.eq_ignore_ascii_case(&row.driver)));
+    }
+
+    /// Clinch and elimination status for every section, given how many events are left in the season
+    pub fn outlook(&self, calculator: &dyn ClinchCalculator, remaining_events: usize) -> Vec<ChampionshipOutlook> {
+        self.sections
+            .iter()
+            .map(|section| ChampionshipOutlook {
+                championship_type: self.championship_type,
+                car_class: section.car_class,
+                remaining_events,
+                drivers: calculator.calculate(&section.drivers(), remaining_events),
+            })
+            .collect()
+    }
+
+    /// Standings for every section after adding hypothetical results for the remaining events
+    pub fn project(
+        &self,
+        calculator: &dyn ScenarioCalculator,
+        hypothetical: &[HypotheticalResult],
+    ) -> Vec<ProjectedStandings> {
+        self.sections
+            .iter()
+            .map(|section| {
+                let section_results = hypothetical
+                    .iter()
+                    .filter(|h| section.car_class.is_none() || h.car_class == section.car_class)
+                    .collect::<Vec<&HypotheticalResult>>();
+                calculator.project(
+                    self.championship_type,
+                    section.car_class,
+                    &section.drivers(),
+                    &section_results,
+                )
+            })
+            .collect()
+    }
+
+    pub fn label_latest_event(&mut self, label: String) {
+        if let Some(latest) = self.event_labels.last_mut() {
+            *latest = label;
+        }
+    }
+
+    pub fn title(&self) -> String {
+        format!(
+            "{} {} Championship -- Best {} of {} Events",
+            self.year,
+            self.championship_type.name(),
+            self.events_to_count,
+            self.event_count
+        )
+    }
+
+    pub fn header(&self) -> Vec<String> {
+        let mut header = vec!["Trophy".to_string(), "Rank".to_string(), "Driver".to_string()];
+        header.extend(self.event_labels.iter().cloned());
+        header.push("Total Points".to_string());
+        header.push(format!("Best {} of {}", self.events_to_count, self.event_count));
+        header
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut rows = vec![
+            self.organization.clone(),
+            self.title(),
+            "".to_string(),
+            self.header().join(","),
+        ];
+        self.sections.iter().for_each(|section| {
+            if let Some(label) = &section.label {
+                rows.push(label.clone());
+            }
+            rows.extend(
+                section
+                    .rows
+                    .iter()
+                    .map(|row| format!("\"{}\"", row.to_record().join("\",\""))),
+            );
+        });
+        rows.join("\n")
+    }
+}
+
+impl ChampionshipSection {
+    pub fn drivers(&self) -> Vec<ChampionshipDriver> {
+        self.rows
+            .iter()
+            .map(|row| {
+                let mut driver = ChampionshipDriver::new(&row.driver);
+                row.points.iter().for_each(|points| driver.add_event(*points));
+                driver
+            })
+            .collect()
+    }
+}
+
+impl ChampionshipRow {
+    pub fn to_record(&self) -> Vec<String> {
+        let mut record = vec![
+            if self.trophy { "T".to_string() } else { "".to_string() },
+            format!("{}", self.rank),
+            self.driver.clone(),
+        ];
+        record.extend(self.points.iter().map(|points| format!("{points}")));
+        record.push(format!("{}", self.total_points));
+        record.push(format!("{}", self.best_of));
+        record
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::enums::championship_type::ChampionshipType;
+    use crate::models::championship_standings::{ChampionshipRow, ChampionshipSection, ChampionshipStandings};
+
+    #[test]
+    fn labels_latest_event_column() {
+        let mut standings = ChampionshipStandings {
+            championship_type: ChampionshipType::PAX,
+            organization: "SCCA".to_string(),
+            year: 2024,
+            event_count: 2,
+            events_to_count: 2,
+            event_labels: ChampionshipStandings::default_event_labels(2),
+            sections: vec![ChampionshipSection {
+                car_class: None,
+                label: None,
+                rows: vec![ChampionshipRow {
+                    trophy: true,
+                    rank: 1,
+                    driver: "Name 1".to_string(),
+                    points: vec![9_900, 10_000],
+                    total_points: 19_900,
+                    best_of: 19_900,
+                    visitor: false,
+                }],
+            }],
+        };
+
+        standings.label_latest_event("Spring Opener (2024-04-21)".to_string());
+
+        assert_eq!(
+            standings.to_csv(),
+            "SCCA\n\
+2024 PAX Championship -- Best 2 of 2 Events\n\
+\n\
+Trophy,Rank,Driver,Event #1,Spring Opener (2024-04-21),Total Points,Best 2 of 2\n\
+\"T\",\"1\",\"Name 1\",\"9900\",\"10000\",\"19900\",\"19900\""
+        );
+    }
+}
@@ -0,0 +1,44 @@
+use serde::Serialize;
+
+use crate::enums::short_car_class::ShortCarClass;
+use crate::models::result_time::ResultTime;
+
+/// A single driver's finish within their class at one event
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ClassResultRow {
+    pub trophy: bool,
+    pub position: Option<usize>,
+    pub name: String,
+    pub car: String,
+    /// The driver's own class, or the class they are indexed by when running in Pro
+    pub car_class: Option<ShortCarClass>,
+    pub car_number: u16,
+    pub total_time: ResultTime,
+    pub index_time: ResultTime,
+    pub from_previous: ResultTime,
+    pub from_top: ResultTime,
+    pub points: i64,
+    /// Region of record
+    pub region: String,
+    /// Names of the drivers sharing this car. Not part of the published CSV.
+    pub co_drivers: Vec<String>,
+}
+
+impl ClassResultRow {
+    pub fn to_record(&self) -> Vec<String> {
+        vec![
+            if self.trophy { "T".to_string() } else { "".to_string() },
+            self.position.map(|p| format!("{p}")).unwrap_or_default(),
+            self.name.clone(),
+            self.car.clone(),
+            self.car_class.map(|c| c.name()).unwrap_or_default().to_string(),
+            format!("{}", self.car_number),
+            self.total_time.display.clone(),
+            self.index_time.display.clone(),
+            self.from_previous.display.clone(),
+            self.from_top.display.clone(),
+            format!("{}", self.points),
+            self.region.clone(),
+        ]
+    }
+}
@@ -83,7 +83,11 @@ mod test {
                 make: None,
                 model: None,
                 color: None,
+                member_number: None,
+                rookie: None,
+                ladies: None,
                 dsq: None,
+                region: None,
                 best_run: "".to_string(),
                 pax_multiplier: "0.0".to_string(),
                 pax_time: "0.0".to_string(),
@@ -100,6 +104,7 @@ mod test {
                 medical: None,
                 novice: Some(0),
                 ladies: Some(0),
+                co_driver_number: None,
             },
         ))
     }
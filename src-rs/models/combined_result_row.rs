@@ -0,0 +1,44 @@
+use serde::Serialize;
+
+use crate::enums::short_car_class::ShortCarClass;
+use crate::models::result_time::ResultTime;
+
+/// A single driver's finish within an overall group (PAX, Raw, Novice or Ladies) at one event
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct CombinedResultRow {
+    pub trophy: bool,
+    pub position: usize,
+    pub name: String,
+    pub car: String,
+    pub car_class: ShortCarClass,
+    pub car_number: u16,
+    /// Raw time in the Raw group, indexed time everywhere else
+    pub time: ResultTime,
+    pub from_previous: ResultTime,
+    pub from_top: ResultTime,
+    /// Not awarded in the Raw group
+    pub points: Option<i64>,
+    /// Region of record
+    pub region: String,
+}
+
+impl CombinedResultRow {
+    pub fn to_record(&self) -> Vec<String> {
+        let mut record = vec![
+            if self.trophy { "T".to_string() } else { "".to_string() },
+            format!("{}", self.position),
+            self.name.clone(),
+            self.car.clone(),
+            self.car_class.name().to_string(),
+            format!("{}", self.car_number),
+            self.time.display.clone(),
+            self.from_previous.display.clone(),
+            self.from_top.display.clone(),
+        ];
+        if let Some(points) = self.points {
+            record.push(format!("{points}"));
+        }
+        record.push(self.region.clone());
+        record
+    }
+}
@@ -0,0 +1,84 @@
+use serde::Serialize;
+
+use crate::enums::short_car_class::ShortCarClass;
+
+/// How consistently each driver, and each class as a whole, ran at an event. Rates are percentages of the runs a
+/// driver actually took; runs never taken and re-runs are left out.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ConsistencyReport {
+    pub drivers: Vec<DriverConsistency>,
+    pub classes: Vec<ClassConsistency>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct DriverConsistency {
+    pub driver: String,
+    pub car_class: ShortCarClass,
+    pub runs: usize,
+    pub clean_runs: usize,
+    /// Seconds gained from the first run to the best run, cone penalties included. `None` if either was not timed.
+    pub improvement: Option<f64>,
+    /// Standard deviation of the raw times of runs without cones or penalties, needing at least two such runs
+    pub clean_run_std_dev: Option<f64>,
+    /// Percentage of runs with at least one cone
+    pub cone_rate: f64,
+    pub dnf_rate: f64,
+    /// Percentage of runs within 1% of the driver's best run
+    pub within_one_percent: f64,
+}
+
+/// Averages across every driver in a class. Rates are pooled over all of the class's runs.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ClassConsistency {
+    pub car_class: ShortCarClass,
+    pub drivers: usize,
+    pub runs: usize,
+    pub average_improvement: Option<f64>,
+    pub average_clean_run_std_dev: Option<f64>,
+    pub cone_rate: f64,
+    pub dnf_rate: f64,
+    pub within_one_percent: f64,
+}
+
+impl ConsistencyReport {
+    pub fn to_csv(&self) -> String {
+        let optional = |value: Option<f64>| value.map(|v| format!("{v:.3}")).unwrap_or_default();
+        let mut rows = vec![
+            "Driver,Class,Runs,Clean Runs,Improvement,Clean Run Std Dev,Cone Rate,DNF Rate,Within 1% of Best"
+                .to_string(),
+        ];
+        rows.extend(self.drivers.iter().map(|d| {
+            format!(
+                "\"{}\",{},{},{},{},{},{:.1},{:.1},{:.1}",
+                d.driver,
+                d.car_class.name(),
+                d.runs,
+                d.clean_runs,
+                optional(d.improvement),
+                optional(d.clean_run_std_dev),
+                d.cone_rate,
+                d.dnf_rate,
+                d.within_one_percent
+            )
+        }));
+        rows.push("".to_string());
+        rows.push(
+            "Class,Drivers,Runs,Average Improvement,Average Clean Run Std Dev,Cone Rate,DNF Rate,Within 1% of Best"
+                .to_string(),
+        );
+        rows.extend(self.classes.iter().map(|c| {
+            format!(
+                "{},{},{},{},{},{:.1},{:.1},{:.1}",
+                c.car_class.name(),
+                c.drivers,
+                c.runs,
+                optional(c.average_improvement),
+                optional(c.average_clean_run_std_dev),
+                c.cone_rate,
+                c.dnf_rate,
+                c.within_one_percent
+            )
+        }));
+        rows.join("\n")
+    }
+}
@@ -1,3 +1,4 @@
+use crate::enums::class_source::ClassSource;
 use crate::enums::short_car_class::ShortCarClass;
 use crate::models::car_class::{get_car_class, CarClass};
 use crate::models::driver_from_pronto::DriverFromPronto;
@@ -12,6 +13,7 @@ pub struct Driver {
     pub error: bool,
     pub id: DriverId,
     pub name: String,
+    pub member_number: String,
     pub car_number: u16,
     pub car_class: CarClass,
     pub pax_class: Option<CarClass>,
@@ -23,10 +25,21 @@ pub struct Driver {
     pub dsq: bool,
     pub pax_multiplier: PaxMultiplier,
     pub times: Vec<LapTime>,
+    /// Car number of this driver's co-driver, when registration says so
+    pub co_driver_number: Option<u16>,
+    /// Names of the drivers sharing this car; see [`Driver::is_co_driver_of()`]
+    pub co_drivers: Vec<String>,
+    /// From outside the home regions; see [`crate::models::region_policy::RegionPolicy`]
+    pub visitor: bool,
+    /// Not an eligible SCCA member; see [`crate::models::membership::MembershipRules`]
+    pub non_member: bool,
+    /// Graduated, but still entered as a novice; see [`crate::models::novice_graduation::GraduationRules`]
+    pub graduated: bool,
 }
 
-impl From<(DriverFromPronto, &MsrDriver)> for Driver {
-    fn from((driver, msr_driver): (DriverFromPronto, &MsrDriver)) -> Self {
+impl From<DriverFromPronto> for Driver {
+    /// Build a driver purely from the Pronto export, trusting its classification flags
+    fn from(driver: DriverFromPronto) -> Self {
         let best_run_is_falsy = driver
             .best_run
             .parse::<f64>()
@@ -41,40 +54,86 @@ impl From<(DriverFromPronto, &MsrDriver)> for Driver {
             .clone()
             .unwrap_or_else(|| "<Missing Last Name>".to_string());
 
-        let (car_class, pax_class) = if msr_driver.class_and_pax.starts_with("P") {
-            (ShortCarClass::P, ShortCarClass::parse(&msr_driver.class_and_pax[1..]))
-        } else {
-            (
-                ShortCarClass::parse(&msr_driver.class_and_pax).unwrap_or(ShortCarClass::AM),
-                None,
-            )
-        };
-        let car_class = match get_car_class(&car_class) {
+        let car_class = match get_car_class(&driver.car_class) {
             Some(c) => c,
             None => panic!("Unable to map class for driver {}", driver.car_class.name()),
         };
-        let pax_class = pax_class.as_ref().and_then(get_car_class);
 
         Driver {
             error: driver.runs.is_empty() && !best_run_is_falsy,
-            rookie: msr_driver.novice.unwrap_or_default() != 0,
-            ladies_championship: msr_driver.ladies.unwrap_or_default() != 0,
+            rookie: driver.rookie.unwrap_or_default() != 0,
+            ladies_championship: driver.ladies.unwrap_or_default() != 0,
             position: None,
             car_number: driver.car_number,
             car_class,
-            pax_class,
+            pax_class: None,
             name: format!("{first_name} {last_name}"),
             id: driver.id(),
+            member_number: driver.member_number.clone().unwrap_or_default().trim().to_string(),
             car_description: format!(
                 "{} {} {}",
                 driver.year.unwrap_or(0),
                 driver.make.clone().unwrap_or_else(|| "Unknown".to_string()),
                 driver.model.clone().unwrap_or_else(|| "Unknown".to_string())
             ),
-            region: msr_driver.region.clone().unwrap_or_default(),
+            region: driver.region.clone().unwrap_or_default().trim().to_string(),
             dsq: driver.dsq.map(|dsq| dsq == 1).unwrap_or(false),
             pax_multiplier: PaxMultiplier::from_str(&driver.pax_multiplier).unwrap(),
             times: driver.runs,
+            co_driver_number: None,
+            co_drivers: vec![],
+            visitor: false,
+            non_member: false,
+            graduated: false,
+        }
+    }
+}
+
+impl From<(DriverFromPronto, &MsrDriver)> for Driver {
+    fn from((driver, msr_driver): (DriverFromPronto, &MsrDriver)) -> Self {
+        Driver::from((driver, msr_driver, ClassSource::Pronto))
+    }
+}
+
+impl From<(DriverFromPronto, &MsrDriver, ClassSource)> for Driver {
+    /// Build a driver from the Pronto export, preferring MSR for classification flags and the given source for class
+    fn from((driver, msr_driver, class_source): (DriverFromPronto, &MsrDriver, ClassSource)) -> Self {
+        let (car_class, pax_class) = reconcile_class(driver.car_class, msr_driver.car_classes(), class_source);
+        let car_class = match get_car_class(&car_class) {
+            Some(c) => c,
+            None => panic!("Unable to map class for driver {}", driver.car_class.name()),
+        };
+        let pax_class = pax_class.as_ref().and_then(get_car_class);
+
+        Driver {
+            car_class,
+            pax_class,
+            rookie: msr_driver.novice.unwrap_or_default() != 0,
+            ladies_championship: msr_driver.ladies.unwrap_or_default() != 0,
+            member_number: msr_driver.member_number.trim().to_string(),
+            region: msr_driver.region.clone().unwrap_or_default(),
+            co_driver_number: msr_driver.co_driver_number.and_then(|n| u16::try_from(n).ok()),
+            ..Driver::from(driver)
+        }
+    }
+}
+
+/// Pick the class (and PAX class, for Pro drivers) a driver should be scored in. MSR's class is only used when it is
+/// the preferred source and could be parsed; the Pronto class is never silently replaced by a default.
+pub fn reconcile_class(
+    pronto_class: ShortCarClass,
+    msr_classes: Option<(ShortCarClass, Option<ShortCarClass>)>,
+    class_source: ClassSource,
+) -> (ShortCarClass, Option<ShortCarClass>) {
+    match (class_source, msr_classes) {
+        (_, None) => (pronto_class, None),
+        (ClassSource::MSR, Some(msr_classes)) => msr_classes,
+        (ClassSource::Pronto, Some((msr_class, msr_pax_class))) => {
+            if pronto_class == ShortCarClass::P && msr_class == ShortCarClass::P {
+                (pronto_class, msr_pax_class)
+            } else {
+                (pronto_class, None)
+            }
         }
     }
 }
@@ -84,6 +143,22 @@ impl Driver {
         self.position = Some(position);
     }
 
+    /// Two drivers of the same car: either names the other's car number as its co-driver, or their car numbers are
+    /// 100 apart as co-drivers are numbered and they drove the same year, make and model. Pronto's car description has
+    /// no color, so the vehicle alone is not enough to tell two cars apart.
+    pub fn is_co_driver_of(&self, other: &Driver) -> bool {
+        self.id != other.id
+            && (self.co_driver_number == Some(other.car_number)
+                || other.co_driver_number == Some(self.car_number)
+                || (self.car_number.abs_diff(other.car_number) == 100
+                    && self.car_description.to_lowercase() == other.car_description.to_lowercase()))
+    }
+
+    /// The class whose PAX index this driver runs on: their own car's class for Pro drivers
+    pub fn index_class(&self) -> ShortCarClass {
+        self.pax_class.unwrap_or(self.car_class).short
+    }
+
     pub fn best_standard_lap(&self) -> LapTime {
         self.best_lap_in_range(false)
     }
@@ -100,6 +175,15 @@ impl Driver {
         }
     }
 
+    /// Index into [`Driver::times`] of the run that counts as this driver's best, if any run counts at all
+    pub fn best_lap_index(&self, pro: bool) -> Option<usize> {
+        let best_lap = self.best_lap(pro);
+        best_lap.time.as_ref()?;
+        Self::lap_times(&self.times, pro)
+            .iter()
+            .position(|lap| lap.time.is_some() && lap == &best_lap)
+    }
+
     fn best_lap_in_range(&self, best_of_three: bool) -> LapTime {
         if self.dsq {
             dsq()
@@ -152,8 +236,9 @@ impl Driver {
 
 #[cfg(test)]
 mod test {
+    use crate::enums::class_source::ClassSource;
     use crate::enums::short_car_class::ShortCarClass;
-    use crate::models::driver::Driver;
+    use crate::models::driver::{reconcile_class, Driver};
     use crate::models::driver_from_pronto::DriverFromPronto;
     use crate::models::lap_time::{dns, dsq, LapTime, Penalty};
     use crate::models::msr_driver::MsrDriver;
@@ -173,7 +258,11 @@ mod test {
                 make: None,
                 model: None,
                 color: None,
+                member_number: None,
+                rookie: None,
+                ladies: None,
                 dsq: Some(if dsq { 1 } else { 0 }),
+                region: None,
                 best_run: "".to_string(),
                 pax_multiplier: "0.5".to_string(),
                 pax_time: "0.0".to_string(),
@@ -190,6 +279,7 @@ mod test {
                 medical: None,
                 novice: Default::default(),
                 ladies: Default::default(),
+                co_driver_number: None,
             },
         ))
     }
@@ -375,4 +465,52 @@ mod test {
 
         assert_eq!(actual, vec![d1, d2, d3]);
     }
+
+    #[rstest]
+    #[case(ShortCarClass::SS, None, ClassSource::MSR, (ShortCarClass::SS, None))]
+    #[case(ShortCarClass::SS, Some((ShortCarClass::AS, None)), ClassSource::Pronto, (ShortCarClass::SS, None))]
+    #[case(ShortCarClass::SS, Some((ShortCarClass::AS, None)), ClassSource::MSR, (ShortCarClass::AS, None))]
+    #[case(
+        ShortCarClass::P,
+        Some((ShortCarClass::P, Some(ShortCarClass::SS))),
+        ClassSource::Pronto,
+        (ShortCarClass::P, Some(ShortCarClass::SS))
+    )]
+    #[case(
+        ShortCarClass::SS,
+        Some((ShortCarClass::P, Some(ShortCarClass::SS))),
+        ClassSource::Pronto,
+        (ShortCarClass::SS, None)
+    )]
+    fn reconcile_class_happy_path(
+        #[case] pronto_class: ShortCarClass,
+        #[case] msr_classes: Option<(ShortCarClass, Option<ShortCarClass>)>,
+        #[case] class_source: ClassSource,
+        #[case] expected: (ShortCarClass, Option<ShortCarClass>),
+    ) {
+        assert_eq!(reconcile_class(pronto_class, msr_classes, class_source), expected);
+    }
+
+    #[rstest]
+    #[case("SS", Some((ShortCarClass::SS, None)))]
+    #[case("P", Some((ShortCarClass::P, None)))]
+    #[case("PSS", Some((ShortCarClass::P, Some(ShortCarClass::SS))))]
+    #[case(" CAMC ", Some((ShortCarClass::CAMC, None)))]
+    #[case("Bogus", None)]
+    fn msr_car_classes(#[case] class_and_pax: &str, #[case] expected: Option<(ShortCarClass, Option<ShortCarClass>)>) {
+        let msr_driver = MsrDriver {
+            last_name: "".to_string(),
+            first_name: "".to_string(),
+            member_number: "".to_string(),
+            class_and_pax: class_and_pax.to_string(),
+            car_number: 0,
+            car: "".to_string(),
+            region: None,
+            medical: None,
+            novice: None,
+            co_driver_number: None,
+            ladies: None,
+        };
+        assert_eq!(msr_driver.car_classes(), expected);
+    }
 }
@@ -24,8 +24,16 @@ pub struct DriverFromPronto {
     pub model: Option<String>,
     #[serde(rename = "Car Color")]
     pub color: Option<String>,
+    #[serde(rename = "Member #")]
+    pub member_number: Option<String>,
+    #[serde(rename = "Rookie")]
+    pub rookie: Option<u8>,
+    #[serde(rename = "Ladies")]
+    pub ladies: Option<u8>,
     #[serde(rename = "DSQ")]
     pub dsq: Option<u8>,
+    #[serde(rename = "Region")]
+    pub region: Option<String>,
     #[serde(rename = "Best Run")]
     pub best_run: String,
     #[serde(rename = "Pax Index")]
@@ -0,0 +1,127 @@
+use serde::Serialize;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// Descriptive details of a single event, supplied alongside the Pronto export and carried into every published
+/// output. Exposed to JS as `EventMetadata` so it does not shadow the DOM's `Event`.
+#[wasm_bindgen(js_name = EventMetadata, getter_with_clone)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Event {
+    pub name: String,
+    /// ISO-8601 date, such as `2024-04-21`
+    pub date: Option<String>,
+    pub location: Option<String>,
+    pub event_number: Option<u16>,
+    pub organizer: Option<String>,
+    pub chief_of_timing: Option<String>,
+    pub course_length: Option<String>,
+}
+
+#[wasm_bindgen(js_class = EventMetadata)]
+impl Event {
+    #[wasm_bindgen(constructor)]
+    pub fn new(name: String) -> Event {
+        Event {
+            name,
+            ..Default::default()
+        }
+    }
+}
+
+impl Event {
+    /// The event name, falling back to its number when it has not been named
+    pub fn title(&self) -> String {
+        match (self.name.trim(), self.event_number) {
+            ("", Some(number)) => format!("Event #{number}"),
+            ("", None) => "Event Results".to_string(),
+            (name, _) => name.to_string(),
+        }
+    }
+
+    /// Lines describing the event, for the top of published results. Details that were not supplied are skipped.
+    pub fn header_lines(&self) -> Vec<String> {
+        let join = |parts: Vec<Option<String>>| {
+            let parts = parts.into_iter().flatten().collect::<Vec<String>>();
+            if parts.is_empty() {
+                None
+            } else {
+                Some(parts.join(" | "))
+            }
+        };
+        vec![
+            Some(self.title()),
+            join(vec![
+                // An unnamed event is already titled by its number
+                self.event_number
+                    .filter(|_| !self.name.trim().is_empty())
+                    .map(|number| format!("Event #{number}")),
+                self.date.clone(),
+                self.location.clone(),
+            ]),
+            join(vec![
+                self.organizer.clone(),
+                self.chief_of_timing.as_ref().map(|c| format!("Chief of Timing: {c}")),
+                self.course_length.as_ref().map(|l| format!("Course Length: {l}")),
+            ]),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
+    /// Label for this event's column in championship standings, or `None` if the event was never named. Commas are
+    /// dropped since championship headers are written unquoted.
+    pub fn column_label(&self) -> Option<String> {
+        let name = self.name.trim();
+        if name.is_empty() {
+            None
+        } else {
+            let label = match &self.date {
+                Some(date) => format!("{name} ({date})"),
+                None => name.to_string(),
+            };
+            Some(label.replace(',', ""))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::models::event::Event;
+
+    #[test]
+    fn unnamed_event_falls_back_to_number() {
+        let event = Event {
+            event_number: Some(3),
+            ..Default::default()
+        };
+        assert_eq!(event.title(), "Event #3");
+        assert_eq!(event.column_label(), None);
+        assert_eq!(event.header_lines(), vec!["Event #3"]);
+    }
+
+    #[test]
+    fn named_event_describes_every_detail() {
+        let event = Event {
+            name: "Spring Opener, Round 1".to_string(),
+            date: Some("2024-04-21".to_string()),
+            location: Some("World Wide Technology Raceway".to_string()),
+            event_number: Some(1),
+            organizer: Some("St. Louis Region SCCA".to_string()),
+            chief_of_timing: Some("David Zemon".to_string()),
+            course_length: Some("0.7 mi".to_string()),
+        };
+        assert_eq!(event.title(), "Spring Opener, Round 1");
+        assert_eq!(
+            event.column_label(),
+            Some("Spring Opener Round 1 (2024-04-21)".to_string())
+        );
+        assert_eq!(
+            event.header_lines(),
+            vec![
+                "Spring Opener, Round 1",
+                "Event #1 | 2024-04-21 | World Wide Technology Raceway",
+                "St. Louis Region SCCA | Chief of Timing: David Zemon | Course Length: 0.7 mi",
+            ]
+        );
+    }
+}
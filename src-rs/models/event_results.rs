@@ -4,12 +4,20 @@ use wasm_bindgen::JsValue;
 
 use crate::enums::driver_group::DriverGroup;
 use crate::enums::short_car_class::ShortCarClass;
+use crate::models::audit_finding::AuditFinding;
 use crate::models::class_results::ClassResults;
 use crate::models::driver::Driver;
+use crate::models::event::Event;
+use crate::models::source_discrepancy::SourceDiscrepancy;
+use crate::services::audit::event_auditor::EventAuditor;
 
 #[derive(Clone, Debug)]
 pub struct EventResults {
+    pub event: Event,
     pub results: HashMap<ShortCarClass, ClassResults>,
+    pub discrepancies: Vec<SourceDiscrepancy>,
+    /// Registrations that never took a run; they are excluded from results and points
+    pub no_shows: Vec<Driver>,
 }
 
 impl EventResults {
@@ -19,20 +27,96 @@ impl EventResults {
         self.results
             .values()
             .flat_map(|class_results| class_results.drivers.iter().filter(|d| d.error))
-            .map(|driver| {
-                JsValue::from_str(
-                    format!(
-                        "{} ({} {})",
-                        driver.name,
-                        driver.car_number,
-                        driver.car_class.short.name()
+            .map(Self::js_driver_descriptor)
+            .collect()
+    }
+
+    /// Driver descriptors (string consisting of name + number + class) of every registration that
+    /// did not take a run
+    pub fn js_no_shows(&self) -> Vec<JsValue> {
+        self.no_shows.iter().map(Self::js_driver_descriptor).collect()
+    }
+
+    fn js_driver_descriptor(driver: &Driver) -> JsValue {
+        JsValue::from_str(
+            format!(
+                "{} ({} {})",
+                driver.name,
+                driver.car_number,
+                driver.car_class.short.name()
+            )
+            .as_str(),
+        )
+    }
+
+    /// Run the default audit rule set over every driver and run in these results
+    pub fn audit(&self) -> Vec<AuditFinding> {
+        EventAuditor::default().audit(self)
+    }
+
+    /// See [`EventResults::audit()`]
+    pub fn js_audit(&self) -> Vec<JsValue> {
+        self.audit()
+            .iter()
+            .map(|finding| {
+                serde_wasm_bindgen::to_value(finding).unwrap_or_else(|_| {
+                    panic!(
+                        "Failed to serialize {} audit finding for {}",
+                        finding.category.name(),
+                        finding.driver_name
                     )
-                    .as_str(),
-                )
+                })
             })
             .collect()
     }
 
+    /// Every field that differed between the Pronto and MSR exports during import
+    pub fn js_source_discrepancies(&self) -> Vec<JsValue> {
+        self.discrepancies
+            .iter()
+            .map(|discrepancy| {
+                serde_wasm_bindgen::to_value(discrepancy).unwrap_or_else(|_| {
+                    panic!(
+                        "Failed to serialize {} discrepancy for {}",
+                        discrepancy.field, discrepancy.driver_name
+                    )
+                })
+            })
+            .collect()
+    }
+
+    /// A copy without the `excluded` drivers, with class positions recalculated among the drivers that remain
+    pub fn without(&self, excluded: impl Fn(&Driver) -> bool) -> EventResults {
+        let mut results: HashMap<ShortCarClass, ClassResults> = HashMap::new();
+        self.results
+            .iter()
+            .flat_map(|(class, class_results)| class_results.drivers.iter().map(move |d| (*class, d)))
+            .filter(|(_, d)| !excluded(d))
+            .for_each(|(class, d)| {
+                results
+                    .entry(class)
+                    .or_insert_with(|| ClassResults::new(class))
+                    .add_driver(d.clone())
+            });
+        EventResults {
+            results,
+            no_shows: self.no_shows.iter().filter(|d| !excluded(d)).cloned().collect(),
+            ..self.clone()
+        }
+    }
+
+    /// Results for every class, ordered by class category and then by class
+    pub fn sorted_class_results(&self) -> Vec<&ClassResults> {
+        let mut results = self.results.values().collect::<Vec<&ClassResults>>();
+        results.sort_by(|lhs, rhs| {
+            lhs.car_class
+                .category
+                .cmp(&rhs.car_class.category)
+                .then(lhs.car_class.short.cmp(&rhs.car_class.short))
+        });
+        results
+    }
+
     /// Get a sorted list of drivers
     pub fn get_drivers(&self, filter: Option<DriverGroup>) -> Vec<&Driver> {
         let filter = filter.unwrap_or(DriverGroup::PAX);
@@ -54,4 +138,14 @@ impl EventResults {
         });
         drivers
     }
+
+    /// Drivers with a PAX time, fastest first. Fun class and disqualified drivers are left out, as they are from the
+    /// PAX championship, so every season analysis measures the same field.
+    pub fn get_pax_field(&self) -> Vec<&Driver> {
+        self.get_drivers(None)
+            .into_iter()
+            .filter(|d| d.car_class.short != ShortCarClass::FUN && !d.dsq)
+            .filter(|d| d.best_standard_lap().with_pax().is_some())
+            .collect()
+    }
 }
@@ -0,0 +1,48 @@
+use serde::Serialize;
+
+/// Run and work assignments for an event. Each heat works the course while the next heat runs, and the last heat works
+/// the first.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct HeatRoster {
+    pub heats: Vec<Heat>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Heat {
+    pub number: usize,
+    pub works_heat: usize,
+    /// Classes running in this heat, apart from co-drivers moved here from another heat
+    pub classes: Vec<String>,
+    pub entries: Vec<HeatEntry>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct HeatEntry {
+    pub driver: String,
+    pub car_class: String,
+    pub car_number: usize,
+    pub car: String,
+    /// Co-drivers of this car, who are in other heats
+    pub co_drivers: Vec<String>,
+}
+
+impl HeatRoster {
+    pub fn to_csv(&self) -> String {
+        let mut rows = vec!["Heat,Works Heat,Class,Number,Driver,Car,Co-Drivers".to_string()];
+        self.heats.iter().for_each(|heat| {
+            rows.extend(heat.entries.iter().map(|entry| {
+                format!(
+                    "{},{},{},{},\"{}\",\"{}\",\"{}\"",
+                    heat.number,
+                    heat.works_heat,
+                    entry.car_class,
+                    entry.car_number,
+                    entry.driver,
+                    entry.car.replace('"', "\"\""),
+                    entry.co_drivers.join(", ")
+                )
+            }))
+        });
+        rows.join("\n")
+    }
+}
@@ -14,6 +14,17 @@ pub enum Penalty {
     DNS,
 }
 
+impl Penalty {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Penalty::DNF => "DNF",
+            Penalty::RRN => "RRN",
+            Penalty::DSQ => "DSQ",
+            Penalty::DNS => "DNS",
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct LapTime {
     // Even though this isn't used, it can be helpful in debugging
@@ -26,6 +37,8 @@ pub struct LapTime {
     pub rerun: bool,
     pub dsq: bool,
     pub dns: bool,
+    /// Whether timing recorded a non-zero time for this run, even if a penalty discarded it
+    pub timed: bool,
 }
 
 impl LapTime {
@@ -73,6 +86,21 @@ impl LapTime {
         }
     }
 
+    /// The penalty, if any, that discarded this run's time
+    pub fn penalty(&self) -> Option<Penalty> {
+        if self.dnf {
+            Some(Penalty::DNF)
+        } else if self.rerun {
+            Some(Penalty::RRN)
+        } else if self.dsq {
+            Some(Penalty::DSQ)
+        } else if self.dns {
+            Some(Penalty::DNS)
+        } else {
+            None
+        }
+    }
+
     pub fn with_pax(&self) -> Option<Time> {
         self.time.clone().map(|_| self.bigs().2)
     }
@@ -88,6 +116,7 @@ impl LapTime {
 
 impl LapTime {
     pub fn new(raw_time: Time, pax: PaxMultiplier, cones: u8, penalty: Option<Penalty>) -> LapTime {
+        let timed = raw_time > Time::from(0);
         match penalty {
             None => LapTime {
                 raw: Some(raw_time.clone()),
@@ -98,6 +127,7 @@ impl LapTime {
                 rerun: false,
                 dsq: false,
                 dns: false,
+                timed,
             },
             Some(Penalty::DNF) => LapTime {
                 raw: None,
@@ -108,6 +138,7 @@ impl LapTime {
                 rerun: false,
                 dsq: false,
                 dns: false,
+                timed,
             },
             Some(Penalty::RRN) => LapTime {
                 raw: None,
@@ -118,6 +149,7 @@ impl LapTime {
                 rerun: true,
                 dsq: false,
                 dns: false,
+                timed,
             },
             Some(Penalty::DSQ) => LapTime {
                 raw: None,
@@ -128,6 +160,7 @@ impl LapTime {
                 rerun: false,
                 dsq: true,
                 dns: false,
+                timed,
             },
             Some(Penalty::DNS) => LapTime {
                 raw: None,
@@ -138,6 +171,7 @@ impl LapTime {
                 rerun: false,
                 dsq: false,
                 dns: true,
+                timed,
             },
         }
     }
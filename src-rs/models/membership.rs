@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::enums::membership_status::MembershipStatus;
+use crate::models::type_aliases::DriverId;
+
+/// One row of a local SCCA membership roster export
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct RosterMember {
+    #[serde(rename = "Member #")]
+    pub member_number: String,
+    #[serde(rename = "Name")]
+    pub name: String,
+    /// `YYYY-MM-DD` or `MM/DD/YYYY` in the export, always `YYYY-MM-DD` once the roster is parsed
+    #[serde(rename = "Expires")]
+    pub expires: String,
+    /// Such as `Weekend`, `Individual` or `Family`. Only present in exports that include it.
+    #[serde(rename = "Type", default)]
+    pub membership_type: Option<String>,
+}
+
+impl RosterMember {
+    pub fn is_weekend(&self) -> bool {
+        self.membership_type
+            .as_ref()
+            .is_some_and(|t| t.to_lowercase().contains("weekend"))
+    }
+}
+
+/// How a region treats membership when scoring its championships
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MembershipRules {
+    /// Weekend members count as members
+    pub weekend_members_eligible: bool,
+    /// Take drivers who are not eligible members out of championship scoring. They keep their event results.
+    pub exclude_non_members: bool,
+}
+
+#[wasm_bindgen]
+impl MembershipRules {
+    #[wasm_bindgen(constructor)]
+    pub fn new(weekend_members_eligible: bool, exclude_non_members: bool) -> MembershipRules {
+        MembershipRules {
+            weekend_members_eligible,
+            exclude_non_members,
+        }
+    }
+}
+
+impl MembershipRules {
+    pub fn is_eligible(&self, status: MembershipStatus) -> bool {
+        match status {
+            MembershipStatus::Member => true,
+            MembershipStatus::Weekend => self.weekend_members_eligible,
+            _ => false,
+        }
+    }
+}
+
+/// A driver's membership, as registered and as found on the roster
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct MembershipCheck {
+    pub id: DriverId,
+    pub driver: String,
+    pub member_number: String,
+    pub status: MembershipStatus,
+    /// Name on the roster for the registered member number
+    pub roster_name: Option<String>,
+    pub expires: Option<String>,
+    /// The registered member number belongs to someone else on the roster
+    pub name_mismatch: bool,
+    /// Eligible for championship scoring under the region's rules
+    pub eligible: bool,
+}
@@ -1,10 +1,31 @@
+pub mod audit_finding;
 pub mod car_class;
 pub mod championship_driver;
+pub mod championship_outlook;
 pub mod championship_results;
+pub mod championship_standings;
+pub mod class_result_row;
 pub mod class_results;
+pub mod combined_result_row;
+pub mod consistency_report;
 pub mod driver;
 pub mod driver_from_pronto;
+pub mod event;
 pub mod event_results;
+pub mod heat_roster;
 pub mod lap_time;
+pub mod membership;
 pub mod msr_driver;
+pub mod novice_graduation;
+pub mod organization_rules;
+pub mod pax_fairness;
+pub mod rating;
+pub mod region_policy;
+pub mod report_card;
+pub mod result_time;
+pub mod scenario;
+pub mod season_normalization;
+pub mod series_definition;
+pub mod series_result_row;
+pub mod source_discrepancy;
 pub mod type_aliases;
@@ -1,3 +1,4 @@
+use crate::enums::short_car_class::ShortCarClass;
 use crate::models::type_aliases::DriverId;
 use serde::Deserialize;
 
@@ -23,6 +24,9 @@ pub struct MsrDriver {
     pub novice: Option<u8>,
     #[serde(rename = "Ladies")]
     pub ladies: Option<u8>,
+    /// Only present in exports that include it, such as the All Inclusive report
+    #[serde(rename = "Co Driver Number", default)]
+    pub co_driver_number: Option<usize>,
 }
 
 impl MsrDriver {
@@ -31,4 +35,29 @@ impl MsrDriver {
             .trim()
             .to_string()
     }
+
+    /// Class and, for Pro drivers, PAX class from the "Class + Modifier/PAX" column. `None` if the column cannot be
+    /// parsed.
+    pub fn car_classes(&self) -> Option<(ShortCarClass, Option<ShortCarClass>)> {
+        let class_and_pax = self.class_and_pax.trim();
+        match ShortCarClass::parse(class_and_pax) {
+            Some(car_class) => Some((car_class, None)),
+            None => match class_and_pax.strip_prefix("P") {
+                Some(pax_class) => ShortCarClass::parse(pax_class).map(|pax_class| (ShortCarClass::P, Some(pax_class))),
+                None => None,
+            },
+        }
+    }
+
+    /// Two registrations for the same car, by the same rule as
+    /// [`Driver::is_co_driver_of()`](crate::models::driver::Driver::is_co_driver_of): either names the other's
+    /// car number as its co-driver, or their car numbers are 100 apart as co-drivers are numbered and they registered
+    /// the same vehicle
+    pub fn is_co_driver_of(&self, other: &MsrDriver) -> bool {
+        let vehicle = |d: &MsrDriver| d.car.trim().to_lowercase();
+        self.id() != other.id()
+            && (self.co_driver_number == Some(other.car_number)
+                || other.co_driver_number == Some(self.car_number)
+                || (self.car_number.abs_diff(other.car_number) == 100 && vehicle(self) == vehicle(other)))
+    }
 }
@@ -0,0 +1,61 @@
+use serde::Serialize;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::models::type_aliases::DriverId;
+
+/// When a novice stops being a novice: after driving `max_events` events, or after finishing in the top `top_pax`
+/// on PAX at any event
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct GraduationRules {
+    pub max_events: usize,
+    pub top_pax: usize,
+    /// Take graduates out of the Novice championship even if MSR still marks them as novices
+    pub exclude_graduates: bool,
+}
+
+#[wasm_bindgen]
+impl GraduationRules {
+    #[wasm_bindgen(constructor)]
+    pub fn new(max_events: usize, top_pax: usize, exclude_graduates: bool) -> GraduationRules {
+        GraduationRules {
+            max_events,
+            top_pax,
+            exclude_graduates,
+        }
+    }
+}
+
+/// A driver who has been entered as a novice at any event so far, and how close they are to graduating
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct NoviceStatus {
+    pub id: DriverId,
+    pub driver: String,
+    pub events: usize,
+    pub class_trophies: usize,
+    pub novice_trophies: usize,
+    pub best_pax_position: Option<usize>,
+    /// The event after which the driver graduated
+    pub graduated_after: Option<String>,
+    pub reason: Option<String>,
+    pub entered_as_novice: bool,
+    /// Graduated, but still entered as a novice at the current event
+    pub flagged: bool,
+}
+
+impl NoviceStatus {
+    pub fn new(id: DriverId, driver: String) -> NoviceStatus {
+        NoviceStatus {
+            id,
+            driver,
+            events: 0,
+            class_trophies: 0,
+            novice_trophies: 0,
+            best_pax_position: None,
+            graduated_after: None,
+            reason: None,
+            entered_as_novice: false,
+            flagged: false,
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::models::driver::Driver;
+use crate::services::calculators::championship_points_calculator::{
+    ChampionshipPointsCalculator, ScaledChampionshipPointsCalculator,
+};
+use crate::services::calculators::trophy_calculator::{LimitedTrophyCalculator, TrophyCalculator};
+
+/// How one organization scores its own championships at a joint event. Only drivers whose region of record is one of
+/// `home_regions` are scored, so each region's drivers count toward their own region's championships.
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrganizationRules {
+    /// Matches the organization named in the prior standings sheet
+    pub organization: String,
+    pub home_regions: Vec<String>,
+    /// Points for winning a class or indexed group
+    pub max_event_points: i64,
+    /// Most trophies in a class, or in the Novice and Ladies championships
+    pub class_trophies: usize,
+    pub pax_trophies: usize,
+}
+
+#[wasm_bindgen]
+impl OrganizationRules {
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        organization: String,
+        home_regions: Vec<String>,
+        max_event_points: i64,
+        class_trophies: usize,
+        pax_trophies: usize,
+    ) -> OrganizationRules {
+        OrganizationRules {
+            organization,
+            home_regions,
+            max_event_points,
+            class_trophies,
+            pax_trophies,
+        }
+    }
+}
+
+impl OrganizationRules {
+    /// Unlike [`crate::models::region_policy::RegionPolicy`], drivers without a region of record are not scored by
+    /// anyone, since there is no telling which region they belong to
+    pub fn scores(&self, driver: &Driver) -> bool {
+        let region = driver.region.trim();
+        !region.is_empty()
+            && self
+                .home_regions
+                .iter()
+                .any(|home| home.trim().eq_ignore_ascii_case(region))
+    }
+
+    pub fn points_calculator(&self) -> Box<dyn ChampionshipPointsCalculator> {
+        Box::new(ScaledChampionshipPointsCalculator {
+            max_points: self.max_event_points,
+        })
+    }
+
+    pub fn trophy_calculator(&self) -> Box<dyn TrophyCalculator> {
+        Box::new(LimitedTrophyCalculator {
+            class_trophies: self.class_trophies,
+            pax_trophies: self.pax_trophies,
+        })
+    }
+}
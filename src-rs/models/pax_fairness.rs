@@ -0,0 +1,74 @@
+use serde::Serialize;
+
+/// How each class and class category fared in the PAX standings over a season. Gaps are the percentage a driver's
+/// indexed time was behind the event's PAX winner.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct PaxFairnessReport {
+    pub events: usize,
+    /// Median gap across every result in the season, which each group is compared against
+    pub field_median_gap: f64,
+    pub classes: Vec<PaxFairness>,
+    pub categories: Vec<PaxFairness>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct PaxFairness {
+    pub group: String,
+    /// The multiplier used at the latest event, only for classes
+    pub pax_multiplier: Option<f64>,
+    /// Driver-event results counted, which should be weighed before acting on a suggestion
+    pub results: usize,
+    pub drivers: usize,
+    pub average_pax_rank: f64,
+    /// Average of each rank as a percentage of the event's PAX entries, comparable across events of different sizes
+    pub average_pax_percentile: f64,
+    pub best_gap: f64,
+    pub first_quartile_gap: f64,
+    pub median_gap: f64,
+    pub third_quartile_gap: f64,
+    pub worst_gap: f64,
+    /// Factor that would bring the group's median gap in line with the field's; above 1 means the group is
+    /// under-indexed and its multiplier could rise
+    pub suggested_adjustment: f64,
+    pub suggested_pax_multiplier: Option<f64>,
+}
+
+impl PaxFairnessReport {
+    pub fn to_csv(&self) -> String {
+        let mut rows = vec![format!(
+            "PAX Fairness over {} Events -- Field Median Gap {:.3}%",
+            self.events, self.field_median_gap
+        )];
+        for (label, groups) in [("Class", &self.classes), ("Category", &self.categories)] {
+            rows.push("".to_string());
+            rows.push(format!(
+                "{label},PAX Multiplier,Results,Drivers,Average PAX Rank,Average PAX Percentile,Best Gap %,\
+                 25th Percentile Gap %,Median Gap %,75th Percentile Gap %,Worst Gap %,Suggested Adjustment,\
+                 Suggested PAX Multiplier"
+            ));
+            rows.extend(groups.iter().map(PaxFairness::to_record).map(|record| record.join(",")));
+        }
+        rows.join("\n")
+    }
+}
+
+impl PaxFairness {
+    pub fn to_record(&self) -> Vec<String> {
+        let multiplier = |value: Option<f64>| value.map(|v| format!("{v:.3}")).unwrap_or_default();
+        vec![
+            self.group.clone(),
+            multiplier(self.pax_multiplier),
+            self.results.to_string(),
+            self.drivers.to_string(),
+            format!("{:.1}", self.average_pax_rank),
+            format!("{:.1}", self.average_pax_percentile),
+            format!("{:.3}", self.best_gap),
+            format!("{:.3}", self.first_quartile_gap),
+            format!("{:.3}", self.median_gap),
+            format!("{:.3}", self.third_quartile_gap),
+            format!("{:.3}", self.worst_gap),
+            format!("{:.4}", self.suggested_adjustment),
+            multiplier(self.suggested_pax_multiplier),
+        ]
+    }
+}
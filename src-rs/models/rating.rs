@@ -0,0 +1,135 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::type_aliases::DriverId;
+
+/// Rating every driver starts from
+pub const INITIAL_RATING: f64 = 1500.;
+
+/// Drivers rated at fewer events than this are still provisional; their ratings move faster and are flagged
+pub const PROVISIONAL_EVENTS: usize = 3;
+
+/// Everything needed to carry ratings from one event to the next, across seasons. Callers keep this between events
+/// and pass it back in; it serializes to plain objects and arrays so it survives `JSON.stringify`.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct RatingState {
+    pub events_rated: usize,
+    pub drivers: Vec<DriverRating>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct DriverRating {
+    pub id: DriverId,
+    pub driver: String,
+    /// From head-to-head results against everyone on PAX
+    pub pax: f64,
+    /// From head-to-head results against the rest of the driver's class at each event
+    pub class: f64,
+    pub events: usize,
+    pub history: Vec<RatingChange>,
+}
+
+/// Ratings after one event
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct RatingChange {
+    pub event: String,
+    pub pax: f64,
+    pub class: f64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct LeaderboardRow {
+    pub rank: usize,
+    pub driver: String,
+    pub pax: f64,
+    pub class: f64,
+    pub events: usize,
+    pub provisional: bool,
+    /// Change in PAX rating at the driver's latest event
+    pub last_change: f64,
+}
+
+impl RatingState {
+    /// Highest PAX rating first
+    pub fn leaderboard(&self) -> Vec<LeaderboardRow> {
+        let mut drivers = self.drivers.iter().collect::<Vec<&DriverRating>>();
+        drivers.sort_by(|lhs, rhs| rhs.pax.total_cmp(&lhs.pax).then_with(|| lhs.driver.cmp(&rhs.driver)));
+        drivers
+            .into_iter()
+            .enumerate()
+            .map(|(index, d)| {
+                let previous = d
+                    .history
+                    .len()
+                    .checked_sub(2)
+                    .map_or(INITIAL_RATING, |i| d.history[i].pax);
+                LeaderboardRow {
+                    rank: index + 1,
+                    driver: d.driver.clone(),
+                    pax: d.pax,
+                    class: d.class,
+                    events: d.events,
+                    provisional: d.events < PROVISIONAL_EVENTS,
+                    last_change: d.pax - previous,
+                }
+            })
+            .collect()
+    }
+
+    pub fn leaderboard_csv(&self) -> String {
+        let mut rows = vec!["Rank,Driver,PAX Rating,Class Rating,Events,Provisional,Last Change".to_string()];
+        rows.extend(self.leaderboard().iter().map(|row| {
+            format!(
+                "{},\"{}\",{:.0},{:.0},{},{},{:+.0}",
+                row.rank,
+                row.driver,
+                row.pax,
+                row.class,
+                row.events,
+                if row.provisional { "Yes" } else { "" },
+                row.last_change
+            )
+        }));
+        rows.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::models::rating::{DriverRating, RatingChange, RatingState};
+
+    fn rating(name: &str, history: &[f64]) -> DriverRating {
+        DriverRating {
+            id: name.to_lowercase(),
+            driver: name.to_string(),
+            pax: *history.last().unwrap(),
+            class: 1500.,
+            events: history.len(),
+            history: history
+                .iter()
+                .map(|pax| RatingChange {
+                    event: "".to_string(),
+                    pax: *pax,
+                    class: 1500.,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn leaderboard_ranks_by_pax_rating() {
+        let state = RatingState {
+            events_rated: 3,
+            drivers: vec![
+                rating("Slow Driver", &[1490., 1480.]),
+                rating("Fast Driver", &[1510., 1520., 1535.]),
+            ],
+        };
+
+        assert_eq!(
+            state.leaderboard_csv(),
+            "Rank,Driver,PAX Rating,Class Rating,Events,Provisional,Last Change\n\
+             1,\"Fast Driver\",1535,1500,3,,+15\n\
+             2,\"Slow Driver\",1480,1500,2,Yes,-10"
+        );
+    }
+}
@@ -0,0 +1,57 @@
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::enums::visitor_policy::VisitorPolicy;
+
+/// Which regions of record count as home for the championship, and what happens to everyone else. Visitors always
+/// keep their event results; `visitors` decides whether they are left out of championship scoring or only flagged.
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RegionPolicy {
+    pub home_regions: Vec<String>,
+    pub visitors: VisitorPolicy,
+}
+
+#[wasm_bindgen]
+impl RegionPolicy {
+    #[wasm_bindgen(constructor)]
+    pub fn new(home_regions: Vec<String>, visitors: VisitorPolicy) -> RegionPolicy {
+        RegionPolicy { home_regions, visitors }
+    }
+}
+
+impl RegionPolicy {
+    /// Regions are compared ignoring case. Drivers without a region of record are given the benefit of the doubt, so
+    /// a missing MSR export never drops anyone, and an empty home-region set makes nobody a visitor.
+    pub fn is_visitor(&self, region: &str) -> bool {
+        let region = region.trim();
+        !region.is_empty()
+            && !self.home_regions.is_empty()
+            && !self
+                .home_regions
+                .iter()
+                .any(|home| home.trim().eq_ignore_ascii_case(region))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use crate::enums::visitor_policy::VisitorPolicy;
+    use crate::models::region_policy::RegionPolicy;
+
+    #[rstest]
+    #[case(&["STL"], "STL", false)]
+    #[case(&["STL"], " stl ", false)]
+    #[case(&["STL", "KC"], "KC", false)]
+    #[case(&["STL"], "KC", true)]
+    #[case(&["STL"], "", false)]
+    #[case(&[], "KC", false)]
+    fn identifies_visitors(#[case] home_regions: &[&str], #[case] region: &str, #[case] expected: bool) {
+        let policy = RegionPolicy::new(
+            home_regions.iter().map(|r| r.to_string()).collect(),
+            VisitorPolicy::Exclude,
+        );
+        assert_eq!(policy.is_visitor(region), expected);
+    }
+}
@@ -0,0 +1,42 @@
+use serde::Serialize;
+
+use crate::enums::championship_type::ChampionshipType;
+use crate::enums::short_car_class::ShortCarClass;
+use crate::models::result_time::ResultTime;
+
+/// One driver's season at a glance
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ReportCard {
+    pub driver: String,
+    pub events: Vec<ReportCardEvent>,
+    /// Every championship the driver scored in at least once; one per class for the class championship
+    pub championships: Vec<ReportCardChampionship>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ReportCardEvent {
+    pub event: String,
+    pub attended: bool,
+    pub car_class: Option<ShortCarClass>,
+    pub class_position: Option<usize>,
+    pub class_entries: usize,
+    pub pax_position: Option<usize>,
+    pub pax_entries: usize,
+    pub gap_to_class_leader: Option<ResultTime>,
+    /// Best PAX time against the event's baseline; see [`crate::models::season_normalization::SeasonNormalization`]
+    pub normalized_score: Option<f64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ReportCardChampionship {
+    pub championship_type: ChampionshipType,
+    pub car_class: Option<ShortCarClass>,
+    /// Points at each event, or `None` where the driver did not score in this championship
+    pub points: Vec<Option<i64>>,
+    pub events_to_count: usize,
+    pub counted_total: i64,
+    /// Event numbers, starting from 1
+    pub best_event: Option<usize>,
+    pub worst_counted_event: Option<usize>,
+    pub dropped_events: Vec<usize>,
+}
@@ -0,0 +1,37 @@
+use serde::Serialize;
+
+/// A time or gap as it is published, along with its numeric value when it has one. Penalties such as `DNF` and
+/// empty gaps carry no value.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ResultTime {
+    pub seconds: Option<f64>,
+    pub display: String,
+}
+
+impl ResultTime {
+    pub fn new(display: String) -> ResultTime {
+        ResultTime {
+            seconds: display.parse::<f64>().ok(),
+            display,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use crate::models::result_time::ResultTime;
+
+    #[rstest]
+    #[case("52.288", Some(52.288))]
+    #[case("0.512", Some(0.512))]
+    #[case("DNF", None)]
+    #[case("N/A", None)]
+    #[case("", None)]
+    fn parses_display(#[case] display: &str, #[case] expected: Option<f64>) {
+        let actual = ResultTime::new(display.to_string());
+        assert_eq!(actual.seconds, expected);
+        assert_eq!(actual.display, display);
+    }
+}
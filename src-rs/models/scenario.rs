@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+use crate::enums::championship_type::ChampionshipType;
+use crate::enums::short_car_class::ShortCarClass;
+
+/// Points a driver is assumed to score at each of the remaining events
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct HypotheticalResult {
+    pub driver: String,
+    /// Class the driver competes in for the class championship; ignored for indexed championships
+    pub car_class: Option<ShortCarClass>,
+    pub points: Vec<i64>,
+}
+
+/// Standings of one championship, or of one class of the class championship, after the hypothetical results
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ProjectedStandings {
+    pub championship_type: ChampionshipType,
+    pub car_class: Option<ShortCarClass>,
+    pub future_events: usize,
+    pub trophy_count: usize,
+    /// Ordered by projected rank
+    pub drivers: Vec<ProjectedDriver>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ProjectedDriver {
+    pub driver: String,
+    /// `None` for drivers who only appear in the hypothetical results
+    pub current_rank: Option<usize>,
+    pub projected_rank: usize,
+    /// Places gained, negative when the driver drops
+    pub rank_change: Option<i64>,
+    pub current_total: i64,
+    pub projected_total: i64,
+    pub trophy: bool,
+}
@@ -0,0 +1,57 @@
+use serde::Serialize;
+
+/// Driver performance made comparable across events held on different courses and in different conditions. A score
+/// is the driver's best PAX time as a percentage of the event's baseline, so 100 is baseline pace and lower is faster.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct SeasonNormalization {
+    pub baselines: Vec<EventBaseline>,
+    /// Fastest average score first
+    pub drivers: Vec<NormalizedDriver>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct EventBaseline {
+    pub event: String,
+    /// Median of the fastest PAX times, or `None` if nobody set a time
+    pub baseline: Option<f64>,
+    pub drivers_in_baseline: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct NormalizedDriver {
+    pub driver: String,
+    /// Score at each event, or `None` where the driver did not set a time
+    pub scores: Vec<Option<f64>>,
+    pub average_score: f64,
+    pub best_score: f64,
+}
+
+impl SeasonNormalization {
+    pub fn to_csv(&self) -> String {
+        let mut header = vec!["Driver".to_string()];
+        header.extend(self.baselines.iter().map(|b| b.event.clone()));
+        header.push("Average Score".to_string());
+        header.push("Best Score".to_string());
+
+        let mut baselines = vec!["Baseline".to_string()];
+        baselines.extend(
+            self.baselines
+                .iter()
+                .map(|b| b.baseline.map(|t| format!("{t:.3}")).unwrap_or_default()),
+        );
+
+        let mut rows = vec![header.join(","), baselines.join(",")];
+        rows.extend(self.drivers.iter().map(|d| {
+            let mut cells = vec![format!("\"{}\"", d.driver)];
+            cells.extend(
+                d.scores
+                    .iter()
+                    .map(|s| s.map(|s| format!("{s:.2}")).unwrap_or_default()),
+            );
+            cells.push(format!("{:.2}", d.average_score));
+            cells.push(format!("{:.2}", d.best_score));
+            cells.join(",")
+        }));
+        rows.join("\n")
+    }
+}
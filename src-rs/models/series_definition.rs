@@ -0,0 +1,57 @@
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::enums::short_car_class::ShortCarClass;
+
+/// A divisional or national series that some events feed, with its own points
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SeriesDefinition {
+    pub name: String,
+    /// Short class names, such as `AS`
+    pub classes: Vec<String>,
+    /// Points by class position, starting with the winner. When empty, points are awarded on time, the same as for
+    /// championships.
+    pub points_table: Vec<i64>,
+    /// Only drivers with a member number, who were not found to be non-members, are submitted
+    pub members_only: bool,
+}
+
+#[wasm_bindgen]
+impl SeriesDefinition {
+    #[wasm_bindgen(constructor)]
+    pub fn new(name: String, classes: Vec<String>, points_table: Vec<i64>, members_only: bool) -> SeriesDefinition {
+        SeriesDefinition {
+            name,
+            classes,
+            points_table,
+            members_only,
+        }
+    }
+}
+
+impl SeriesDefinition {
+    pub fn car_classes(&self) -> Result<Vec<ShortCarClass>, String> {
+        self.classes
+            .iter()
+            .map(|class| {
+                ShortCarClass::parse(class.trim().to_uppercase().as_str())
+                    .ok_or_else(|| format!("Unknown class {class} in {}", self.name))
+            })
+            .collect()
+    }
+
+    /// `None` if the series awards points on time instead. Positions past the end of the table score nothing.
+    pub fn table_points(&self, position: usize) -> Option<i64> {
+        if self.points_table.is_empty() {
+            None
+        } else {
+            Some(
+                position
+                    .checked_sub(1)
+                    .and_then(|i| self.points_table.get(i))
+                    .copied()
+                    .unwrap_or(0),
+            )
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use serde::Serialize;
+
+use crate::enums::short_car_class::ShortCarClass;
+
+/// A single driver's finish as submitted to a series
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct SeriesResultRow {
+    pub series: String,
+    pub event: String,
+    pub date: String,
+    pub region: String,
+    pub car_class: ShortCarClass,
+    pub position: usize,
+    pub driver: String,
+    pub member_number: String,
+    pub car_number: u16,
+    pub car: String,
+    pub time: String,
+    pub points: i64,
+}
+
+impl SeriesResultRow {
+    pub fn header() -> Vec<String> {
+        [
+            "Series", "Event", "Date", "Region", "Class", "Position", "Driver", "Member #", "Car #", "Car", "Time",
+            "Points",
+        ]
+        .iter()
+        .map(|cell| cell.to_string())
+        .collect()
+    }
+
+    pub fn to_record(&self) -> Vec<String> {
+        vec![
+            self.series.clone(),
+            self.event.clone(),
+            self.date.clone(),
+            self.region.clone(),
+            self.car_class.name().to_string(),
+            format!("{}", self.position),
+            self.driver.clone(),
+            self.member_number.clone(),
+            format!("{}", self.car_number),
+            self.car.clone(),
+            self.time.clone(),
+            format!("{}", self.points),
+        ]
+    }
+}
@@ -0,0 +1,141 @@
+use serde::Serialize;
+
+use crate::enums::class_source::ClassSource;
+use crate::models::driver::{reconcile_class, Driver};
+use crate::models::driver_from_pronto::DriverFromPronto;
+use crate::models::msr_driver::MsrDriver;
+use crate::models::type_aliases::{DriverId, PaxMultiplier};
+
+/// A single field for which the Pronto export and the MSR registration export disagree
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct SourceDiscrepancy {
+    pub driver_id: DriverId,
+    pub driver_name: String,
+    pub field: String,
+    pub pronto: String,
+    pub msr: String,
+    /// The value the engine chose when building results
+    pub used: String,
+}
+
+impl SourceDiscrepancy {
+    pub fn new(driver: &DriverFromPronto, field: &str, pronto: String, msr: String, used: String) -> SourceDiscrepancy {
+        SourceDiscrepancy {
+            driver_id: driver.id(),
+            driver_name: format!(
+                "{} {}",
+                driver.first_name.as_ref().unwrap_or(&"".to_string()),
+                driver.last_name.as_ref().unwrap_or(&"".to_string())
+            )
+            .trim()
+            .to_string(),
+            field: field.to_string(),
+            pronto,
+            msr,
+            used,
+        }
+    }
+
+    /// Record a Pronto driver that has no matching registration in the MSR export
+    pub fn missing_from_msr(driver: &DriverFromPronto) -> SourceDiscrepancy {
+        Self::new(
+            driver,
+            "Registration",
+            "Present".to_string(),
+            "Missing".to_string(),
+            "Pronto".to_string(),
+        )
+    }
+
+    /// Record a driver moved to their MSR class being re-indexed from Pronto's PAX index to the one that class uses
+    pub fn reindexed(driver: &Driver, pax: &PaxMultiplier) -> SourceDiscrepancy {
+        SourceDiscrepancy {
+            driver_id: driver.id.clone(),
+            driver_name: driver.name.clone(),
+            field: "PAX".to_string(),
+            pronto: driver.pax_multiplier.to_string(),
+            msr: pax.to_string(),
+            used: pax.to_string(),
+        }
+    }
+
+    /// Compare every field that both exports carry and report those that differ
+    pub fn compare(pronto: &DriverFromPronto, msr: &MsrDriver, class_source: ClassSource) -> Vec<SourceDiscrepancy> {
+        let mut discrepancies = vec![];
+
+        let msr_classes = msr.car_classes();
+        let class_matches = match msr_classes {
+            Some((msr_class, _)) => msr_class == pronto.car_class,
+            None => false,
+        };
+        if !class_matches {
+            let (class, pax_class) = reconcile_class(pronto.car_class, msr_classes, class_source);
+            discrepancies.push(Self::new(
+                pronto,
+                "Class",
+                pronto.car_class.name().to_string(),
+                msr.class_and_pax.trim().to_string(),
+                format!("{}{}", class.name(), pax_class.map(|c| c.name()).unwrap_or_default()),
+            ));
+        }
+
+        let pronto_member_number = pronto.member_number.clone().unwrap_or_default().trim().to_string();
+        if pronto_member_number != msr.member_number.trim() {
+            discrepancies.push(Self::new(
+                pronto,
+                "Member #",
+                pronto_member_number,
+                msr.member_number.trim().to_string(),
+                msr.member_number.trim().to_string(),
+            ));
+        }
+
+        if usize::from(pronto.car_number) != msr.car_number {
+            discrepancies.push(Self::new(
+                pronto,
+                "Number",
+                format!("{}", pronto.car_number),
+                format!("{}", msr.car_number),
+                format!("{}", pronto.car_number),
+            ));
+        }
+
+        let pronto_rookie = pronto.rookie.unwrap_or_default() != 0;
+        let msr_rookie = msr.novice.unwrap_or_default() != 0;
+        if pronto_rookie != msr_rookie {
+            discrepancies.push(Self::new(
+                pronto,
+                "Novice",
+                format!("{pronto_rookie}"),
+                format!("{msr_rookie}"),
+                format!("{msr_rookie}"),
+            ));
+        }
+
+        let pronto_ladies = pronto.ladies.unwrap_or_default() != 0;
+        let msr_ladies = msr.ladies.unwrap_or_default() != 0;
+        if pronto_ladies != msr_ladies {
+            discrepancies.push(Self::new(
+                pronto,
+                "Ladies",
+                format!("{pronto_ladies}"),
+                format!("{msr_ladies}"),
+                format!("{msr_ladies}"),
+            ));
+        }
+
+        let pronto_region = pronto.region.clone().unwrap_or_default().trim().to_uppercase();
+        let msr_region = msr.region.clone().unwrap_or_default().trim().to_uppercase();
+        if pronto_region != msr_region {
+            discrepancies.push(Self::new(
+                pronto,
+                "Region",
+                pronto_region,
+                msr_region.clone(),
+                msr_region,
+            ));
+        }
+
+        discrepancies
+    }
+}
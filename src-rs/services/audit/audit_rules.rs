@@ -0,0 +1,273 @@
+use std::collections::HashMap;
+
+use crate::enums::audit_category::AuditCategory;
+use crate::enums::audit_severity::AuditSeverity;
+use crate::enums::short_car_class::ShortCarClass;
+use crate::models::audit_finding::AuditFinding;
+use crate::models::driver::Driver;
+use crate::models::event_results::EventResults;
+use crate::models::type_aliases::PaxMultiplier;
+
+/// More cones than this on a single run almost always means a typo at the timing table
+pub const MAX_PLAUSIBLE_CONES: u8 = 20;
+
+pub trait AuditRule {
+    fn check(&self, results: &EventResults) -> Vec<AuditFinding>;
+}
+
+fn all_drivers(results: &EventResults) -> impl Iterator<Item = &Driver> {
+    results
+        .results
+        .values()
+        .flat_map(|class_results| class_results.drivers.iter())
+}
+
+/// Drivers that Pronto reports a best run for, but for which no individual runs could be found
+pub struct ImportErrorRule {}
+
+impl AuditRule for ImportErrorRule {
+    fn check(&self, results: &EventResults) -> Vec<AuditFinding> {
+        all_drivers(results)
+            .filter(|d| d.error)
+            .map(|d| {
+                AuditFinding::new(
+                    AuditSeverity::Error,
+                    AuditCategory::Import_Error,
+                    d,
+                    "Pronto reports a best run, but no individual runs were found".to_string(),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Two or more drivers sharing a car number within the same class
+pub struct DuplicateCarNumberRule {}
+
+impl AuditRule for DuplicateCarNumberRule {
+    fn check(&self, results: &EventResults) -> Vec<AuditFinding> {
+        results
+            .results
+            .values()
+            .flat_map(|class_results| {
+                let mut drivers_by_number: HashMap<u16, Vec<&Driver>> = HashMap::new();
+                class_results
+                    .drivers
+                    .iter()
+                    .for_each(|d| drivers_by_number.entry(d.car_number).or_default().push(d));
+                drivers_by_number
+                    .into_values()
+                    .filter(|drivers| drivers.len() > 1)
+                    .flat_map(|drivers| {
+                        let names = drivers
+                            .iter()
+                            .map(|d| d.name.clone())
+                            .collect::<Vec<String>>()
+                            .join(", ");
+                        drivers
+                            .iter()
+                            .map(|d| {
+                                AuditFinding::new(
+                                    AuditSeverity::Error,
+                                    AuditCategory::Duplicate_Car_Number,
+                                    d,
+                                    format!(
+                                        "Car number {} is shared in {} by {names}",
+                                        d.car_number,
+                                        d.car_class.short.name()
+                                    ),
+                                )
+                            })
+                            .collect::<Vec<AuditFinding>>()
+                    })
+                    .collect::<Vec<AuditFinding>>()
+            })
+            .collect()
+    }
+}
+
+/// Drivers without a single run that timing started
+pub struct NoRunsRule {}
+
+impl AuditRule for NoRunsRule {
+    fn check(&self, results: &EventResults) -> Vec<AuditFinding> {
+        all_drivers(results)
+            .filter(|d| !d.error && d.times.iter().all(|lap| lap.dns))
+            .map(|d| {
+                AuditFinding::new(
+                    AuditSeverity::Warning,
+                    AuditCategory::No_Runs,
+                    d,
+                    "Driver has no recorded runs".to_string(),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Runs that were marked DNS even though timing recorded a time for them
+pub struct TimedDnsRule {}
+
+impl AuditRule for TimedDnsRule {
+    fn check(&self, results: &EventResults) -> Vec<AuditFinding> {
+        all_drivers(results)
+            .flat_map(|d| {
+                d.times
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, lap)| lap.dns && lap.timed)
+                    .map(|(index, _)| {
+                        AuditFinding::new(
+                            AuditSeverity::Warning,
+                            AuditCategory::Timed_DNS,
+                            d,
+                            format!("Run {} has a recorded time but is marked DNS", index + 1),
+                        )
+                    })
+                    .collect::<Vec<AuditFinding>>()
+            })
+            .collect()
+    }
+}
+
+/// Runs with an implausible number of cones
+pub struct ExcessiveConesRule {}
+
+impl AuditRule for ExcessiveConesRule {
+    fn check(&self, results: &EventResults) -> Vec<AuditFinding> {
+        all_drivers(results)
+            .flat_map(|d| {
+                d.times
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, lap)| lap.cones > MAX_PLAUSIBLE_CONES)
+                    .map(|(index, lap)| {
+                        AuditFinding::new(
+                            AuditSeverity::Warning,
+                            AuditCategory::Excessive_Cones,
+                            d,
+                            format!(
+                                "Run {} has {} cones, more than the plausible maximum of {MAX_PLAUSIBLE_CONES}",
+                                index + 1,
+                                lap.cones
+                            ),
+                        )
+                    })
+                    .collect::<Vec<AuditFinding>>()
+            })
+            .collect()
+    }
+}
+
+/// Drivers whose PAX index differs from the rest of their class, or whose runs were indexed inconsistently
+pub struct PaxMismatchRule {}
+
+impl AuditRule for PaxMismatchRule {
+    fn check(&self, results: &EventResults) -> Vec<AuditFinding> {
+        results
+            .results
+            .values()
+            .flat_map(|class_results| {
+                let mut counts: HashMap<&PaxMultiplier, usize> = HashMap::new();
+                class_results
+                    .drivers
+                    .iter()
+                    .for_each(|d| *counts.entry(&d.pax_multiplier).or_default() += 1);
+                // Pro drivers are indexed by their own class, so there is no single expected index for P
+                let expected = if class_results.car_class.short == ShortCarClass::P {
+                    None
+                } else {
+                    counts
+                        .into_iter()
+                        .max_by(|(lhs_pax, lhs_count), (rhs_pax, rhs_count)| {
+                            lhs_count.cmp(rhs_count).then(rhs_pax.cmp(lhs_pax))
+                        })
+                        .map(|(pax, _)| pax.clone())
+                };
+
+                class_results
+                    .drivers
+                    .iter()
+                    .flat_map(|d| {
+                        let mut findings = vec![];
+                        if let Some(expected) = expected.as_ref() {
+                            if &d.pax_multiplier != expected {
+                                findings.push(AuditFinding::new(
+                                    AuditSeverity::Error,
+                                    AuditCategory::PAX_Mismatch,
+                                    d,
+                                    format!(
+                                        "PAX index {} does not match index {expected} used by the rest of {}",
+                                        d.pax_multiplier,
+                                        d.car_class.short.name()
+                                    ),
+                                ));
+                            }
+                        }
+                        if d.times.iter().any(|lap| lap.pax != d.pax_multiplier) {
+                            findings.push(AuditFinding::new(
+                                AuditSeverity::Error,
+                                AuditCategory::PAX_Mismatch,
+                                d,
+                                format!("One or more runs are not indexed with PAX index {}", d.pax_multiplier),
+                            ));
+                        }
+                        findings
+                    })
+                    .collect::<Vec<AuditFinding>>()
+            })
+            .collect()
+    }
+}
+
+/// Drivers registered as both a novice and a Pro
+pub struct NoviceInProRule {}
+
+impl AuditRule for NoviceInProRule {
+    fn check(&self, results: &EventResults) -> Vec<AuditFinding> {
+        all_drivers(results)
+            .filter(|d| d.rookie && d.car_class.short == ShortCarClass::P)
+            .map(|d| {
+                AuditFinding::new(
+                    AuditSeverity::Warning,
+                    AuditCategory::Novice_In_Pro,
+                    d,
+                    "Driver is flagged as both Novice and Pro".to_string(),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Registered co-drivers that cannot be matched up: nobody ran under the co-driver's car number, or the two drove
+/// different cars
+pub struct CoDriverRule {}
+
+impl AuditRule for CoDriverRule {
+    fn check(&self, results: &EventResults) -> Vec<AuditFinding> {
+        all_drivers(results)
+            .filter_map(|d| {
+                let number = d.co_driver_number?;
+                let co_drivers = all_drivers(results)
+                    .filter(|other| other.id != d.id && other.car_number == number)
+                    .collect::<Vec<&Driver>>();
+                let message = if co_drivers.is_empty() {
+                    format!("Registered co-driver number {number} did not run")
+                } else if co_drivers
+                    .iter()
+                    .all(|other| other.car_description.to_lowercase() != d.car_description.to_lowercase())
+                {
+                    format!("Registered as co-driver of car {number}, but drove a different car")
+                } else {
+                    return None;
+                };
+                Some(AuditFinding::new(
+                    AuditSeverity::Warning,
+                    AuditCategory::Co_Driver,
+                    d,
+                    message,
+                ))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,163 @@
+use crate::models::audit_finding::AuditFinding;
+use crate::models::event_results::EventResults;
+use crate::services::audit::audit_rules::{
+    AuditRule, CoDriverRule, DuplicateCarNumberRule, ExcessiveConesRule, ImportErrorRule, NoRunsRule, NoviceInProRule,
+    PaxMismatchRule, TimedDnsRule,
+};
+
+/// Run a set of audit rules over event results before they are published
+pub struct EventAuditor {
+    rules: Vec<Box<dyn AuditRule>>,
+}
+
+impl Default for EventAuditor {
+    fn default() -> Self {
+        Self::from(vec![
+            Box::new(ImportErrorRule {}),
+            Box::new(DuplicateCarNumberRule {}),
+            Box::new(NoRunsRule {}),
+            Box::new(TimedDnsRule {}),
+            Box::new(ExcessiveConesRule {}),
+            Box::new(PaxMismatchRule {}),
+            Box::new(NoviceInProRule {}),
+            Box::new(CoDriverRule {}),
+        ])
+    }
+}
+
+impl EventAuditor {
+    pub fn from(rules: Vec<Box<dyn AuditRule>>) -> Self {
+        Self { rules }
+    }
+
+    /// Findings from every rule, errors first, then grouped by class and car number
+    pub fn audit(&self, results: &EventResults) -> Vec<AuditFinding> {
+        let mut findings = self
+            .rules
+            .iter()
+            .flat_map(|rule| rule.check(results))
+            .collect::<Vec<AuditFinding>>();
+        findings.sort_by(|lhs, rhs| {
+            lhs.severity
+                .cmp(&rhs.severity)
+                .then(lhs.car_class.cmp(&rhs.car_class))
+                .then(lhs.car_number.cmp(&rhs.car_number))
+                .then(lhs.category.cmp(&rhs.category))
+        });
+        findings
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::enums::audit_category::AuditCategory;
+    use crate::enums::audit_severity::AuditSeverity;
+    use crate::enums::class_source::ClassSource;
+    use crate::models::event::Event;
+    use crate::services::audit::event_auditor::EventAuditor;
+    use crate::services::csv::parser::event_results_parser::parse;
+
+    // language=csv
+    const PRONTO_DATA: &str = r#"Position, Class, Class Category, Class Name, Number, First Name,Last Name, Car Year, Car Make, Car Model, Car Color, Member #, Rookie, Ladies, DSQ, Region, Best Run, Pax Index, Pax Time, Runs Day1, Runs Day2, Runs (Time/Cones/Penalty)
+"1","SS","Street","Super Street","78","Sean","Greer","2022","Dodge","Challenger","Red","1","0","0","0","STL","41.442","0.83","34.397","2","0","42.429","0","","41.442","0",""
+"2","SS","Street","Super Street","78","Steven","Burkett","2023","Chevrolet","Corvette","Gray","2","0","0","0","STL","44.811","0.83","37.193","2","0","44.811","0","","47.705","21",""
+"3","SS","Street","Super Street","98","John","McDonnell","2023","Chevrolet","Corvette","Gray","3","0","0","0","STL","53.016","0.84","44.533","2","0","53.016","0","","52.100","0","DNS"
+"1","P","Other","Pro Class","96","Matt","Miller","2020","Chevrolet","Camaro","Silver","4","1","0","0","STL","46.994","0.817","38.394","1","0","46.994","0","""#;
+
+    #[test]
+    fn audit_finds_every_rule() {
+        let results = parse(None, PRONTO_DATA.to_string(), ClassSource::Pronto, Event::default()).unwrap();
+
+        let actual = EventAuditor::default()
+            .audit(&results)
+            .into_iter()
+            .map(|f| (f.severity, f.category, f.driver_name))
+            .collect::<Vec<(AuditSeverity, AuditCategory, String)>>();
+
+        assert_eq!(
+            actual,
+            vec![
+                (
+                    AuditSeverity::Error,
+                    AuditCategory::Duplicate_Car_Number,
+                    "Sean Greer".to_string()
+                ),
+                (
+                    AuditSeverity::Error,
+                    AuditCategory::Duplicate_Car_Number,
+                    "Steven Burkett".to_string()
+                ),
+                (
+                    AuditSeverity::Error,
+                    AuditCategory::PAX_Mismatch,
+                    "John McDonnell".to_string()
+                ),
+                (
+                    AuditSeverity::Warning,
+                    AuditCategory::Novice_In_Pro,
+                    "Matt Miller".to_string()
+                ),
+                (
+                    AuditSeverity::Warning,
+                    AuditCategory::Excessive_Cones,
+                    "Steven Burkett".to_string()
+                ),
+                (
+                    AuditSeverity::Warning,
+                    AuditCategory::Timed_DNS,
+                    "John McDonnell".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn audit_reports_drivers_without_runs() {
+        // language=csv
+        let pronto_data = r#"Position, Class, Class Category, Class Name, Number, First Name,Last Name, Car Year, Car Make, Car Model, Car Color, Member #, Rookie, Ladies, DSQ, Region, Best Run, Pax Index, Pax Time, Runs Day1, Runs Day2, Runs (Time/Cones/Penalty)
+"1","SS","Street","Super Street","78","Sean","Greer","2022","Dodge","Challenger","Red","1","0","0","0","STL","","0.83","","1","0","0","0","DNS""#;
+        let results = parse(None, pronto_data.to_string(), ClassSource::Pronto, Event::default()).unwrap();
+
+        let actual = EventAuditor::default().audit(&results);
+
+        assert_eq!(actual.len(), 1);
+        assert_eq!(actual[0].category, AuditCategory::No_Runs);
+        assert_eq!(actual[0].car_number, 78);
+    }
+
+    #[test]
+    fn audit_reports_unmatched_co_drivers() {
+        // language=csv
+        let msr_data = r###""Last Name","First Name","Member #","Class + Modifier/PAX","No.","Vehicle Year/Make/Model/Color","Region of Record Abbreviation","Medical condition? (Optional)","Is a pro","Novice","Ladies","Co Driver Number"
+Greer,Sean,1,SS,78,Challenger,STL,,0,0,0,12
+McDonnell,John,3,SS,98,Corvette,STL,,0,0,0,96"###;
+        let results = parse(
+            Some(msr_data.to_string()),
+            PRONTO_DATA.to_string(),
+            ClassSource::Pronto,
+            Event::default(),
+        )
+        .unwrap();
+
+        let actual = EventAuditor::default()
+            .audit(&results)
+            .into_iter()
+            .filter(|f| f.category == AuditCategory::Co_Driver)
+            .map(|f| (f.driver_name, f.message))
+            .collect::<Vec<(String, String)>>();
+
+        assert_eq!(
+            actual,
+            vec![
+                (
+                    "Sean Greer".to_string(),
+                    "Registered co-driver number 12 did not run".to_string()
+                ),
+                (
+                    "John McDonnell".to_string(),
+                    "Registered as co-driver of car 96, but drove a different car".to_string()
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,2 @@
+pub(crate) mod audit_rules;
+pub(crate) mod event_auditor;
@@ -2,7 +2,15 @@ use crate::models::driver::Driver;
 use crate::models::lap_time::LapTime;
 use bigdecimal::{BigDecimal, ToPrimitive};
 
+/// Points awarded for winning a class or indexed group
+pub const MAX_EVENT_POINTS: i64 = 10_000;
+
 pub trait ChampionshipPointsCalculator {
+    /// Points for the fastest driver
+    fn max_points(&self) -> i64 {
+        MAX_EVENT_POINTS
+    }
+
     fn calculate(&self, fastest: &LapTime, driver: &Driver, pro: bool) -> i64 {
         let actual = if pro {
             driver.best_pro_lap()
@@ -10,11 +18,13 @@ pub trait ChampionshipPointsCalculator {
             driver.best_standard_lap()
         };
         if fastest == &actual {
-            10_000
+            self.max_points()
         } else {
             match (fastest.with_pax(), actual.with_pax()) {
-                (Some(fastest), Some(actual)) => ((fastest * BigDecimal::from(10_000)) / actual).to_i64().unwrap(),
-                (None, Some(_)) => 10_000,
+                (Some(fastest), Some(actual)) => ((fastest * BigDecimal::from(self.max_points())) / actual)
+                    .to_i64()
+                    .unwrap(),
+                (None, Some(_)) => self.max_points(),
                 _ => 0,
             }
         }
@@ -24,3 +34,14 @@ pub trait ChampionshipPointsCalculator {
 pub struct DefaultChampionshipPointsCalculator {}
 
 impl ChampionshipPointsCalculator for DefaultChampionshipPointsCalculator {}
+
+/// The same formula on another scale, for organizations that award something other than [`MAX_EVENT_POINTS`] for a win
+pub struct ScaledChampionshipPointsCalculator {
+    pub max_points: i64,
+}
+
+impl ChampionshipPointsCalculator for ScaledChampionshipPointsCalculator {
+    fn max_points(&self) -> i64 {
+        self.max_points
+    }
+}
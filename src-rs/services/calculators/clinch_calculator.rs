@@ -0,0 +1,146 @@
+use crate::models::championship_driver::ChampionshipDriver;
+use crate::models::championship_outlook::ClinchStatus;
+use crate::services::calculators::championship_points_calculator::MAX_EVENT_POINTS;
+use crate::utilities::events_to_count;
+
+pub trait ClinchCalculator {
+    fn calculate(&self, drivers: &[ChampionshipDriver], remaining_events: usize) -> Vec<ClinchStatus>;
+}
+
+/// Works out each driver's best and worst case at the end of the season, assuming the drop rule from
+/// [`events_to_count()`] applied to the full season and [`MAX_EVENT_POINTS`] for winning an event
+pub struct DefaultClinchCalculator {}
+
+impl ClinchCalculator for DefaultClinchCalculator {
+    fn calculate(&self, drivers: &[ChampionshipDriver], remaining_events: usize) -> Vec<ClinchStatus> {
+        let events_held = drivers.iter().map(|d| d.event_count(true)).max().unwrap_or(0);
+        let counted_now = events_to_count(events_held);
+        let counted_at_end = events_to_count(events_held + remaining_events);
+
+        let mut statuses = drivers
+            .iter()
+            .map(|d| {
+                let mut best_case = d.points().clone();
+                best_case.extend((0..remaining_events).map(|_| MAX_EVENT_POINTS));
+                (
+                    d.name().clone(),
+                    Self::best_of(d.points(), counted_now),
+                    Self::best_of(d.points(), counted_at_end),
+                    Self::best_of(&best_case, counted_at_end),
+                )
+            })
+            .collect::<Vec<(String, i64, i64, i64)>>();
+        statuses.sort_by_key(|(_, current, ..)| std::cmp::Reverse(*current));
+
+        statuses
+            .iter()
+            .enumerate()
+            .map(
+                |(index, (driver, current_total, guaranteed_total, max_possible_total))| {
+                    let others = statuses
+                        .iter()
+                        .enumerate()
+                        .filter(|(other_index, _)| *other_index != index)
+                        .map(|(_, other)| other);
+                    let best_rival_max = others.clone().map(|(.., max)| *max).max();
+                    let best_rival_guaranteed = others.map(|(_, _, guaranteed, _)| *guaranteed).max();
+
+                    let eliminated = best_rival_guaranteed.is_some_and(|rival| *max_possible_total < rival);
+                    let clinched = best_rival_max.is_none_or(|rival| *guaranteed_total > rival);
+                    ClinchStatus {
+                        driver: driver.clone(),
+                        current_total: *current_total,
+                        guaranteed_total: *guaranteed_total,
+                        max_possible_total: *max_possible_total,
+                        clinched,
+                        eliminated,
+                        magic_number: if eliminated {
+                            None
+                        } else {
+                            Some(best_rival_max.map_or(0, |rival| (rival - guaranteed_total + 1).max(0)))
+                        },
+                    }
+                },
+            )
+            .collect()
+    }
+}
+
+impl DefaultClinchCalculator {
+    /// Sum of the best `count` scores, or of all of them when fewer have been scored
+    fn best_of(points: &[i64], count: usize) -> i64 {
+        let mut points = points.to_vec();
+        points.sort();
+        points.iter().rev().take(count).sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::models::championship_driver::ChampionshipDriver;
+    use crate::services::calculators::clinch_calculator::{ClinchCalculator, DefaultClinchCalculator};
+
+    fn driver(name: &str, points: &[i64]) -> ChampionshipDriver {
+        let mut driver = ChampionshipDriver::new(name);
+        points.iter().for_each(|p| driver.add_event(*p));
+        driver
+    }
+
+    #[test]
+    fn leader_clinches_when_no_rival_can_catch_up() {
+        // 7 of 8 events held, so the best 5 count at the end of the season
+        let drivers = vec![
+            driver("Second", &[9_000, 9_000, 9_000, 9_000, 9_000, 0, 0]),
+            driver("Leader", &[10_000, 10_000, 10_000, 10_000, 10_000, 10_000, 0]),
+            driver("Third", &[0, 0, 0, 0, 0, 0, 5_000]),
+        ];
+
+        let actual = DefaultClinchCalculator {}.calculate(&drivers, 1);
+
+        assert_eq!(
+            actual
+                .iter()
+                .map(|s| (
+                    s.driver.as_str(),
+                    s.current_total,
+                    s.guaranteed_total,
+                    s.max_possible_total,
+                    s.clinched,
+                    s.eliminated,
+                    s.magic_number
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                ("Leader", 50_000, 50_000, 50_000, true, false, Some(0)),
+                ("Second", 45_000, 45_000, 46_000, false, true, None),
+                ("Third", 5_000, 5_000, 15_000, false, true, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn open_championship_reports_magic_number() {
+        // 2 of 6 events held, so the best 4 count at the end of the season
+        let drivers = vec![driver("Leader", &[10_000, 10_000]), driver("Second", &[9_500, 9_000])];
+
+        let actual = DefaultClinchCalculator {}.calculate(&drivers, 4);
+
+        assert!(!actual[0].clinched);
+        assert!(!actual[1].eliminated);
+        assert_eq!(actual[0].max_possible_total, 40_000);
+        // Either driver can still win every remaining event
+        assert_eq!(actual[1].max_possible_total, 40_000);
+        assert_eq!(actual[0].magic_number, Some(40_000 - 20_000 + 1));
+        assert_eq!(actual[1].magic_number, Some(40_000 - 18_500 + 1));
+    }
+
+    #[test]
+    fn tied_season_is_neither_clinched_nor_eliminated() {
+        let drivers = vec![driver("One", &[10_000]), driver("Two", &[10_000])];
+
+        let actual = DefaultClinchCalculator {}.calculate(&drivers, 0);
+
+        assert!(actual.iter().all(|s| !s.clinched && !s.eliminated));
+        assert!(actual.iter().all(|s| s.magic_number == Some(1)));
+    }
+}
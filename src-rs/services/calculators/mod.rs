@@ -1,3 +1,6 @@
 pub(crate) mod championship_points_calculator;
+pub(crate) mod clinch_calculator;
+pub(crate) mod rating_calculator;
+pub(crate) mod scenario_calculator;
 pub(crate) mod tie_calculator;
 pub(crate) mod trophy_calculator;
@@ -0,0 +1,263 @@
+use std::cmp::Ordering;
+
+use wasm_bindgen::prelude::wasm_bindgen;
+use wasm_bindgen::JsValue;
+
+use crate::enums::short_car_class::ShortCarClass;
+use crate::models::driver::Driver;
+use crate::models::event::Event;
+use crate::models::event_results::EventResults;
+use crate::models::lap_time::LapTime;
+use crate::models::rating::{DriverRating, RatingChange, RatingState, INITIAL_RATING, PROVISIONAL_EVENTS};
+use crate::services::reports::{event_label, parse_season};
+
+pub trait RatingCalculator {
+    /// Update ratings with one event's results, recording `event` in each rated driver's history
+    fn rate(&self, state: &mut RatingState, results: &EventResults, event: String);
+
+    /// Rate events in season order. Events without a description are numbered after those already rated, so a
+    /// second season continues the numbering rather than repeating it.
+    fn rate_season(&self, state: &mut RatingState, season: &[EventResults]) {
+        season.iter().for_each(|results| {
+            let event = event_label(results, state.events_rated);
+            self.rate(state, results, event);
+        });
+    }
+}
+
+/// Elo ratings from every head-to-head pairing at an event: each driver against everyone on PAX, and against the rest
+/// of their class for the class rating. An event is worth one game's `k_factor`, split across the driver's opponents,
+/// and twice that while a driver is provisional. PAX ratings cover the [PAX field](EventResults::get_pax_field), and
+/// class ratings the same drivers within each class.
+pub struct DefaultRatingCalculator {
+    k_factor: f64,
+}
+
+impl Default for DefaultRatingCalculator {
+    fn default() -> Self {
+        Self { k_factor: 32. }
+    }
+}
+
+impl RatingCalculator for DefaultRatingCalculator {
+    fn rate(&self, state: &mut RatingState, results: &EventResults, event: String) {
+        let pax_field = results
+            .get_pax_field()
+            .into_iter()
+            .map(|d| (d, d.best_standard_lap()))
+            .collect::<Vec<(&Driver, LapTime)>>();
+        let pax_changes = self.changes(state, &pax_field, |rating| rating.pax);
+
+        let class_changes = results
+            .sorted_class_results()
+            .into_iter()
+            .flat_map(|class_results| {
+                let field = class_results
+                    .drivers
+                    .iter()
+                    .filter(|d| d.car_class.short != ShortCarClass::FUN && !d.dsq)
+                    .map(|d| (d, d.best_lap(class_results.car_class.short == ShortCarClass::P)))
+                    .filter(|(_, lap)| lap.time.is_some())
+                    .collect::<Vec<(&Driver, LapTime)>>();
+                self.changes(state, &field, |rating| rating.class)
+            })
+            .collect::<Vec<(&Driver, f64)>>();
+
+        pax_changes.into_iter().for_each(|(driver, change)| {
+            let rating = Self::find_or_insert(state, driver);
+            rating.pax += change;
+        });
+        class_changes.into_iter().for_each(|(driver, change)| {
+            let rating = Self::find_or_insert(state, driver);
+            rating.class += change;
+        });
+        pax_field.iter().for_each(|(driver, _)| {
+            let rating = Self::find_or_insert(state, driver);
+            rating.events += 1;
+            rating.history.push(RatingChange {
+                event: event.clone(),
+                pax: rating.pax,
+                class: rating.class,
+            });
+        });
+        state.events_rated += 1;
+    }
+}
+
+impl DefaultRatingCalculator {
+    /// Rating change for each driver in a field ordered fastest first, all calculated from ratings before the event
+    fn changes<'a>(
+        &self,
+        state: &RatingState,
+        field: &[(&'a Driver, LapTime)],
+        rating: fn(&DriverRating) -> f64,
+    ) -> Vec<(&'a Driver, f64)> {
+        if field.len() < 2 {
+            return field.iter().map(|(driver, _)| (*driver, 0.)).collect();
+        }
+        let before = field
+            .iter()
+            .map(|(driver, _)| {
+                let existing = state.drivers.iter().find(|r| r.id == driver.id);
+                (
+                    existing.map_or(INITIAL_RATING, rating),
+                    existing.map_or(0, |r| r.events),
+                )
+            })
+            .collect::<Vec<(f64, usize)>>();
+
+        field
+            .iter()
+            .enumerate()
+            .map(|(i, (driver, lap))| {
+                let (own, events) = before[i];
+                let surprise = field
+                    .iter()
+                    .enumerate()
+                    .filter(|(j, _)| *j != i)
+                    .map(|(j, (_, other_lap))| {
+                        let actual = match lap.cmp(other_lap) {
+                            Ordering::Less => 1.,
+                            Ordering::Equal => 0.5,
+                            Ordering::Greater => 0.,
+                        };
+                        let expected = 1. / (1. + 10f64.powf((before[j].0 - own) / 400.));
+                        actual - expected
+                    })
+                    .sum::<f64>();
+                let k_factor = if events < PROVISIONAL_EVENTS {
+                    self.k_factor * 2.
+                } else {
+                    self.k_factor
+                };
+                (*driver, k_factor * surprise / (field.len() - 1) as f64)
+            })
+            .collect()
+    }
+
+    fn find_or_insert<'a>(state: &'a mut RatingState, driver: &Driver) -> &'a mut DriverRating {
+        let index = match state.drivers.iter().position(|r| r.id == driver.id) {
+            Some(index) => index,
+            None => {
+                state.drivers.push(DriverRating {
+                    id: driver.id.clone(),
+                    driver: driver.name.clone(),
+                    pax: INITIAL_RATING,
+                    class: INITIAL_RATING,
+                    events: 0,
+                    history: vec![],
+                });
+                state.drivers.len() - 1
+            }
+        };
+        &mut state.drivers[index]
+    }
+}
+
+/// Read ratings kept by the caller, starting fresh when there are none yet
+pub fn rating_state(state: JsValue) -> Result<RatingState, String> {
+    if state.is_null() || state.is_undefined() {
+        Ok(RatingState::default())
+    } else {
+        serde_wasm_bindgen::from_value(state).map_err(|e| e.to_string())
+    }
+}
+
+/// Rate a season, or several, of Pronto exports in order on top of any existing ratings; see [`parse_season()`]
+#[wasm_bindgen]
+pub fn season_ratings(state: JsValue, pronto_exports: Vec<String>, events: Vec<Event>) -> Result<JsValue, String> {
+    let mut state = rating_state(state)?;
+    DefaultRatingCalculator::default().rate_season(&mut state, &parse_season(pronto_exports, events)?);
+    serde_wasm_bindgen::to_value(&state).map_err(|e| e.to_string())
+}
+
+#[wasm_bindgen]
+pub fn rating_leaderboard(state: JsValue) -> Result<JsValue, String> {
+    serde_wasm_bindgen::to_value(&rating_state(state)?.leaderboard()).map_err(|e| e.to_string())
+}
+
+#[wasm_bindgen]
+pub fn rating_leaderboard_csv(state: JsValue) -> Result<String, String> {
+    Ok(rating_state(state)?.leaderboard_csv())
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use crate::enums::class_source::ClassSource;
+    use crate::models::event::Event;
+    use crate::models::event_results::EventResults;
+    use crate::models::rating::{RatingState, INITIAL_RATING};
+    use crate::services::calculators::rating_calculator::{DefaultRatingCalculator, RatingCalculator};
+    use crate::services::csv::parser::event_results_parser::parse;
+
+    fn event() -> EventResults {
+        let contents = fs::read_to_string("./SampleData/2022/2022_Event1-DavidExport.csv").unwrap();
+        parse(None, contents, ClassSource::Pronto, Event::default()).unwrap()
+    }
+
+    #[test]
+    fn faster_drivers_gain_and_slower_drivers_lose() {
+        let mut state = RatingState::default();
+
+        DefaultRatingCalculator::default().rate(&mut state, &event(), "Event #1".to_string());
+
+        assert_eq!(state.events_rated, 1);
+        let leaderboard = state.leaderboard();
+        let names = leaderboard.iter().map(|row| row.driver.as_str()).collect::<Vec<&str>>();
+        assert_eq!(names, vec!["Robert Fullriede", "Jeffrey Osborn", "Adam Buffa"]);
+        assert_eq!(leaderboard[0].pax, INITIAL_RATING + 32.);
+        assert_eq!(leaderboard[1].pax, INITIAL_RATING);
+        assert_eq!(leaderboard[2].pax, INITIAL_RATING - 32.);
+        // Ratings are zero-sum among equally rated drivers
+        assert_eq!(leaderboard.iter().map(|row| row.pax).sum::<f64>(), INITIAL_RATING * 3.);
+        // Buffa was alone in BS, so the class rating did not move
+        assert_eq!(leaderboard[2].class, INITIAL_RATING);
+        assert_eq!(leaderboard[0].class, INITIAL_RATING + 32.);
+    }
+
+    #[test]
+    fn ratings_carry_over_and_keep_history() {
+        let mut state = RatingState::default();
+        let calculator = DefaultRatingCalculator::default();
+        let event = event();
+
+        calculator.rate(&mut state, &event, "First".to_string());
+        calculator.rate(&mut state, &event, "Second".to_string());
+
+        let fullriede = state.drivers.iter().find(|r| r.id == "robert fullriede").unwrap();
+        assert_eq!(fullriede.events, 2);
+        assert_eq!(
+            fullriede
+                .history
+                .iter()
+                .map(|h| h.event.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["First", "Second"]
+        );
+        // Winning again is less of a surprise from a higher rating
+        let gain = fullriede.history[1].pax - fullriede.history[0].pax;
+        assert!(0. < gain && gain < 32.);
+        assert_eq!(state.leaderboard()[0].last_change, gain);
+    }
+
+    #[test]
+    fn later_seasons_continue_event_numbering() {
+        let mut state = RatingState::default();
+        let calculator = DefaultRatingCalculator::default();
+
+        calculator.rate_season(&mut state, &[event()]);
+        calculator.rate_season(&mut state, &[event(), event()]);
+
+        let fullriede = state.drivers.iter().find(|r| r.id == "robert fullriede").unwrap();
+        assert_eq!(
+            fullriede
+                .history
+                .iter()
+                .map(|h| h.event.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["Event #1", "Event #2", "Event #3"]
+        );
+    }
+}
@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+
+use crate::enums::championship_type::ChampionshipType;
+use crate::enums::short_car_class::ShortCarClass;
+use crate::models::championship_driver::ChampionshipDriver;
+use crate::models::scenario::{HypotheticalResult, ProjectedDriver, ProjectedStandings};
+use crate::services::calculators::tie_calculator::calculate_tie_offset;
+use crate::services::calculators::trophy_calculator::{DefaultTrophyCalculator, TrophyCalculator};
+use crate::utilities::events_to_count;
+
+pub trait ScenarioCalculator {
+    fn project(
+        &self,
+        championship_type: ChampionshipType,
+        car_class: Option<ShortCarClass>,
+        drivers: &[ChampionshipDriver],
+        hypothetical: &[&HypotheticalResult],
+    ) -> ProjectedStandings;
+}
+
+/// Projects standings by adding hypothetical points for the remaining events to every driver, scoring zero for anyone
+/// without a hypothetical result, then re-applying the drop rule and trophy rules for the longer season
+pub struct DefaultScenarioCalculator {
+    trophy_calculator: Box<dyn TrophyCalculator>,
+}
+
+impl Default for DefaultScenarioCalculator {
+    fn default() -> Self {
+        Self::from(None)
+    }
+}
+
+impl ScenarioCalculator for DefaultScenarioCalculator {
+    fn project(
+        &self,
+        championship_type: ChampionshipType,
+        car_class: Option<ShortCarClass>,
+        drivers: &[ChampionshipDriver],
+        hypothetical: &[&HypotheticalResult],
+    ) -> ProjectedStandings {
+        let past_events = drivers.iter().map(|d| d.event_count(true)).max().unwrap_or(0);
+        let future_events = hypothetical.iter().map(|h| h.points.len()).max().unwrap_or(0);
+        let counted_now = events_to_count(past_events);
+        let counted_after = events_to_count(past_events + future_events);
+
+        let current_ranks = Self::rank(drivers.to_vec(), counted_now)
+            .into_iter()
+            .map(|(rank, d)| (d.name().to_lowercase(), (rank, d.best_of(counted_now))))
+            .collect::<HashMap<String, (usize, i64)>>();
+
+        let mut hypothetical_by_name = hypothetical
+            .iter()
+            .map(|h| (h.driver.trim().to_lowercase(), *h))
+            .collect::<HashMap<String, &HypotheticalResult>>();
+        let mut projected = drivers.to_vec();
+        // Drivers who have not scored yet join with zero points for every past event
+        hypothetical
+            .iter()
+            .filter(|h| !current_ranks.contains_key(&h.driver.trim().to_lowercase()))
+            .for_each(|h| {
+                let mut newcomer = ChampionshipDriver::new(h.driver.trim());
+                (0..past_events).for_each(|_| newcomer.add_event(0));
+                projected.push(newcomer);
+            });
+        projected.iter_mut().for_each(|d| {
+            let points = hypothetical_by_name
+                .remove(&d.name().to_lowercase())
+                .map(|h| h.points.clone())
+                .unwrap_or_default();
+            (0..future_events).for_each(|i| d.add_event(points.get(i).copied().unwrap_or(0)));
+        });
+
+        let trophy_count = self.trophy_calculator.calculate(
+            projected
+                .iter()
+                .filter(|d| d.event_count(false) >= counted_after)
+                .count(),
+            Some(championship_type),
+        );
+
+        let drivers = Self::rank(projected, counted_after)
+            .into_iter()
+            .map(|(projected_rank, d)| {
+                let current = current_ranks.get(&d.name().to_lowercase());
+                ProjectedDriver {
+                    driver: d.name().clone(),
+                    current_rank: current.map(|(rank, _)| *rank),
+                    projected_rank,
+                    rank_change: current.map(|(rank, _)| *rank as i64 - projected_rank as i64),
+                    current_total: current.map(|(_, total)| *total).unwrap_or(0),
+                    projected_total: d.best_of(counted_after),
+                    trophy: projected_rank - 1 < trophy_count,
+                }
+            })
+            .collect();
+
+        ProjectedStandings {
+            championship_type,
+            car_class,
+            future_events,
+            trophy_count,
+            drivers,
+        }
+    }
+}
+
+impl DefaultScenarioCalculator {
+    pub fn from(trophy_calculator: Option<Box<dyn TrophyCalculator>>) -> DefaultScenarioCalculator {
+        Self {
+            trophy_calculator: trophy_calculator.unwrap_or_else(|| Box::new(DefaultTrophyCalculator {})),
+        }
+    }
+
+    /// Order drivers by their best `events_to_count` results, sharing a rank when tied
+    fn rank(mut drivers: Vec<ChampionshipDriver>, events_to_count: usize) -> Vec<(usize, ChampionshipDriver)> {
+        drivers.sort_by_key(|d| std::cmp::Reverse(d.best_of(events_to_count)));
+        let ranks = (0..drivers.len())
+            .map(|index| {
+                index + 1
+                    - calculate_tie_offset(&drivers, index, |d1, d2| {
+                        d1.best_of(events_to_count) == d2.best_of(events_to_count)
+                    })
+            })
+            .collect::<Vec<usize>>();
+        ranks.into_iter().zip(drivers).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::enums::championship_type::ChampionshipType;
+    use crate::enums::short_car_class::ShortCarClass;
+    use crate::models::championship_driver::ChampionshipDriver;
+    use crate::models::scenario::HypotheticalResult;
+    use crate::services::calculators::scenario_calculator::{DefaultScenarioCalculator, ScenarioCalculator};
+
+    fn driver(name: &str, points: &[i64]) -> ChampionshipDriver {
+        let mut driver = ChampionshipDriver::new(name);
+        points.iter().for_each(|p| driver.add_event(*p));
+        driver
+    }
+
+    fn hypothetical(name: &str, points: &[i64]) -> HypotheticalResult {
+        HypotheticalResult {
+            driver: name.to_string(),
+            car_class: Some(ShortCarClass::SS),
+            points: points.to_vec(),
+        }
+    }
+
+    #[test]
+    fn projects_rank_changes_and_trophies() {
+        let drivers = vec![
+            driver("Leader", &[10_000, 10_000, 9_000]),
+            driver("Chaser", &[9_500, 9_000, 10_000]),
+            driver("Back Marker", &[8_000, 8_000, 8_000]),
+        ];
+        let chaser_wins = hypothetical("chaser", &[10_000]);
+        let leader_second = hypothetical("Leader", &[9_000]);
+        let newcomer = hypothetical("Newcomer", &[9_800]);
+
+        let actual = DefaultScenarioCalculator::default().project(
+            ChampionshipType::Class,
+            Some(ShortCarClass::SS),
+            &drivers,
+            &[&chaser_wins, &leader_second, &newcomer],
+        );
+
+        assert_eq!(actual.future_events, 1);
+        // Best 3 of 4 count, and only three drivers have scored in enough events to qualify for a trophy
+        assert_eq!(actual.trophy_count, 2);
+        assert_eq!(
+            actual
+                .drivers
+                .iter()
+                .map(|d| (
+                    d.driver.as_str(),
+                    d.current_rank,
+                    d.projected_rank,
+                    d.rank_change,
+                    d.projected_total,
+                    d.trophy
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                ("Chaser", Some(2), 1, Some(1), 29_500, true),
+                ("Leader", Some(1), 2, Some(-1), 29_000, true),
+                ("Back Marker", Some(3), 3, Some(0), 24_000, false),
+                ("Newcomer", None, 4, None, 9_800, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn tied_projection_shares_rank() {
+        let drivers = vec![driver("One", &[10_000]), driver("Two", &[9_000])];
+        let two_wins = hypothetical("Two", &[10_000]);
+        let one_second = hypothetical("One", &[9_000]);
+
+        let actual = DefaultScenarioCalculator::default().project(
+            ChampionshipType::PAX,
+            None,
+            &drivers,
+            &[&two_wins, &one_second],
+        );
+
+        assert_eq!(
+            actual.drivers.iter().map(|d| d.projected_rank).collect::<Vec<usize>>(),
+            vec![1, 1]
+        );
+    }
+}
@@ -15,9 +15,24 @@ pub fn calculate_tie_offset<T: Debug, F: Fn(&T, &T) -> bool>(
             if !cmp(baseline, next_comparison) {
                 return baseline_index - next_index - 1;
             }
-            next_index -= 1;
+            match next_index.checked_sub(1) {
+                Some(index) => next_index = index,
+                None => break,
+            }
         }
 
         baseline_index
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::services::calculators::tie_calculator::calculate_tie_offset;
+
+    #[test]
+    fn tie_with_leader() {
+        let points = vec![100, 100, 100, 50];
+        assert_eq!(calculate_tie_offset(&points, 2, |lhs, rhs| lhs == rhs), 2);
+        assert_eq!(calculate_tie_offset(&points, 3, |lhs, rhs| lhs == rhs), 0);
+    }
+}
@@ -31,11 +31,39 @@ impl TrophyCalculator for DefaultTrophyCalculator {
     }
 }
 
+/// The default rules with different limits: at most `class_trophies` in each class and in the Novice and Ladies
+/// championships, and at most `pax_trophies` in PAX
+pub struct LimitedTrophyCalculator {
+    pub class_trophies: usize,
+    pub pax_trophies: usize,
+}
+
+impl TrophyCalculator for LimitedTrophyCalculator {
+    fn calculate(
+        &self,
+        driver_count: usize,
+        championship_type_opt: Option<ChampionshipType>,
+    ) -> usize {
+        match championship_type_opt {
+            None => 0,
+            Some(ChampionshipType::Class) => {
+                if driver_count == 1 {
+                    min(1, self.class_trophies)
+                } else {
+                    min(self.class_trophies, max(driver_count, 1) - 1)
+                }
+            }
+            Some(ChampionshipType::PAX) => min(self.pax_trophies, driver_count),
+            Some(_) => min(self.class_trophies, driver_count),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::enums::championship_type::ChampionshipType;
     use crate::services::calculators::trophy_calculator::{
-        DefaultTrophyCalculator, TrophyCalculator,
+        DefaultTrophyCalculator, LimitedTrophyCalculator, TrophyCalculator,
     };
 
     #[test]
@@ -95,4 +123,21 @@ mod test {
         assert_eq!(testable.calculate(4, Some(ChampionshipType::Novice)), 3);
         assert_eq!(testable.calculate(5, Some(ChampionshipType::Novice)), 3);
     }
+
+    #[test]
+    fn limited_calculate() {
+        let testable = LimitedTrophyCalculator {
+            class_trophies: 2,
+            pax_trophies: 5,
+        };
+
+        assert_eq!(testable.calculate(0, Some(ChampionshipType::Class)), 0);
+        assert_eq!(testable.calculate(1, Some(ChampionshipType::Class)), 1);
+        assert_eq!(testable.calculate(3, Some(ChampionshipType::Class)), 2);
+        assert_eq!(testable.calculate(6, Some(ChampionshipType::Class)), 2);
+        assert_eq!(testable.calculate(3, Some(ChampionshipType::PAX)), 3);
+        assert_eq!(testable.calculate(12, Some(ChampionshipType::PAX)), 5);
+        assert_eq!(testable.calculate(4, Some(ChampionshipType::Novice)), 2);
+        assert_eq!(testable.calculate(4, None), 0);
+    }
 }
@@ -8,13 +8,14 @@ use crate::enums::short_car_class::ShortCarClass;
 use crate::models::car_class::get_car_class;
 use crate::models::championship_driver::ChampionshipDriver;
 use crate::models::championship_results::ClassChampionshipResults;
+use crate::models::championship_standings::{ChampionshipRow, ChampionshipSection, ChampionshipStandings};
 use crate::services::calculators::tie_calculator::calculate_tie_offset;
 use crate::services::calculators::trophy_calculator::{DefaultTrophyCalculator, TrophyCalculator};
 use crate::utilities::events_to_count;
 
 #[cfg_attr(test, automock)]
 pub trait ClassCsvBuilder {
-    fn create(&self, class: ClassChampionshipResults) -> Result<Option<String>, String>;
+    fn create(&self, class: ClassChampionshipResults) -> Result<Option<ChampionshipStandings>, String>;
 }
 
 pub struct DefaultClassCsvBuilder {
@@ -22,7 +23,7 @@ pub struct DefaultClassCsvBuilder {
 }
 
 impl ClassCsvBuilder for DefaultClassCsvBuilder {
-    fn create(&self, results: ClassChampionshipResults) -> Result<Option<String>, String> {
+    fn create(&self, results: ClassChampionshipResults) -> Result<Option<ChampionshipStandings>, String> {
         let event_count = results
             .drivers_by_class
             .values()
@@ -32,17 +33,6 @@ impl ClassCsvBuilder for DefaultClassCsvBuilder {
             .ok_or("Expected at least one driver in at least one class")?
             .event_count(true);
         let events_to_count = events_to_count(event_count);
-        let header = Self::build_header(events_to_count, event_count);
-
-        let mut rows = vec![
-            results.organization.clone(),
-            format!(
-                "{} Class Championship -- Best {} of {} Events",
-                results.year, events_to_count, event_count
-            ),
-            "".to_string(),
-            header,
-        ];
 
         let mut sorted = results
             .drivers_by_class
@@ -55,39 +45,51 @@ impl ClassCsvBuilder for DefaultClassCsvBuilder {
             .collect::<Vec<(ShortCarClass, Vec<ChampionshipDriver>)>>();
         sorted.sort_by(|(lhs, _), (rhs, _)| lhs.cmp(rhs));
 
-        sorted.iter().for_each(|(class, drivers)| {
-            rows.push(format!(
-                "{} - {}",
-                class.name(),
-                to_display_name(get_car_class(class).unwrap().long)
-            ));
-
-            let trophy_count = self.trophy_calculator.calculate(
-                self.get_qualified_driver_count(class, drivers, events_to_count),
-                Some(ChampionshipType::Class),
-            );
-            rows.extend(drivers.iter().enumerate().map(|(index, d)| {
-                let tie_offset = calculate_tie_offset(drivers, index, |d1, d2| d1.total_points() == d2.total_points());
-
-                let mut driver_row = vec![
-                    if (index - tie_offset) < trophy_count {
-                        "T".to_string()
-                    } else {
-                        "".to_string()
-                    },
-                    format!("{}", index + 1 - tie_offset),
-                    d.name().clone(),
-                ];
-                d.points()
+        let sections = sorted
+            .iter()
+            .map(|(class, drivers)| {
+                let trophy_count = self.trophy_calculator.calculate(
+                    self.get_qualified_driver_count(class, drivers, events_to_count),
+                    Some(ChampionshipType::Class),
+                );
+                let rows = drivers
                     .iter()
-                    .for_each(|points| driver_row.push(format!("{points}")));
-                driver_row.push(format!("{}", d.total_points()));
-                driver_row.push(format!("{}", d.best_of(events_to_count)));
-                format!("\"{}\"", driver_row.join("\",\""))
-            }))
-        });
+                    .enumerate()
+                    .map(|(index, d)| {
+                        let tie_offset =
+                            calculate_tie_offset(drivers, index, |d1, d2| d1.total_points() == d2.total_points());
+                        ChampionshipRow {
+                            trophy: (index - tie_offset) < trophy_count,
+                            rank: index + 1 - tie_offset,
+                            driver: d.name().clone(),
+                            points: d.points().clone(),
+                            total_points: d.total_points(),
+                            best_of: d.best_of(events_to_count),
+                            visitor: false,
+                        }
+                    })
+                    .collect();
+                ChampionshipSection {
+                    car_class: Some(*class),
+                    label: Some(format!(
+                        "{} - {}",
+                        class.name(),
+                        to_display_name(get_car_class(class).unwrap().long)
+                    )),
+                    rows,
+                }
+            })
+            .collect();
 
-        Ok(Some(rows.join("\n")))
+        Ok(Some(ChampionshipStandings {
+            championship_type: ChampionshipType::Class,
+            organization: results.organization,
+            year: results.year,
+            event_count,
+            events_to_count,
+            event_labels: ChampionshipStandings::default_event_labels(event_count),
+            sections,
+        }))
     }
 }
 
@@ -104,15 +106,6 @@ impl DefaultClassCsvBuilder {
         }
     }
 
-    fn build_header(events_to_count: usize, event_count: usize) -> String {
-        let mut header = vec!["Trophy".to_string(), "Rank".to_string(), "Driver".to_string()];
-        header.extend((0..event_count).map(|i| format!("Event #{}", i + 1)));
-        header.push("Total Points".to_string());
-        header.push(format!("Best {events_to_count} of {event_count}"));
-
-        header.join(",")
-    }
-
     /// Qualified drivers are those that participated in enough events
     fn get_qualified_driver_count(
         &self,
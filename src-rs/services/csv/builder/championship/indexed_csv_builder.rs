@@ -4,6 +4,7 @@ use mockall::automock;
 use crate::enums::championship_type::ChampionshipType;
 use crate::models::championship_driver::ChampionshipDriver;
 use crate::models::championship_results::IndexedChampionshipResults;
+use crate::models::championship_standings::{ChampionshipRow, ChampionshipSection, ChampionshipStandings};
 use crate::services::calculators::tie_calculator::calculate_tie_offset;
 use crate::services::calculators::trophy_calculator::{DefaultTrophyCalculator, TrophyCalculator};
 use crate::utilities::events_to_count;
@@ -14,7 +15,7 @@ pub trait IndexedCsvBuilder {
         &self,
         championship_type: ChampionshipType,
         results: IndexedChampionshipResults,
-    ) -> Result<Option<String>, String>;
+    ) -> Result<Option<ChampionshipStandings>, String>;
 }
 
 pub struct DefaultIndexedCsvBuilder {
@@ -26,14 +27,13 @@ impl IndexedCsvBuilder for DefaultIndexedCsvBuilder {
         &self,
         championship_type: ChampionshipType,
         results: IndexedChampionshipResults,
-    ) -> Result<Option<String>, String> {
+    ) -> Result<Option<ChampionshipStandings>, String> {
         let event_count = results
             .drivers
             .first()
             .ok_or("Expected at least one driver")?
             .event_count(true);
         let events_to_count = events_to_count(event_count);
-        let header = Self::build_header(event_count);
         let trophy_count = self.trophy_calculator.calculate(
             results
                 .drivers
@@ -44,18 +44,6 @@ impl IndexedCsvBuilder for DefaultIndexedCsvBuilder {
             Some(championship_type),
         );
 
-        let mut rows = vec![
-            results.organization.clone(),
-            format!(
-                "{} {} Championship -- Best {} of {} Events",
-                results.year,
-                championship_type.name(),
-                events_to_count,
-                event_count
-            ),
-            "".to_string(),
-            header,
-        ];
         let mut sorted = results.drivers;
         sorted.sort_by_key(|d| d.best_of(events_to_count));
         sorted.reverse();
@@ -64,29 +52,38 @@ impl IndexedCsvBuilder for DefaultIndexedCsvBuilder {
             .iter()
             .filter(|d| d.total_points() != 0)
             .collect::<Vec<&ChampionshipDriver>>();
-        rows.extend(filtered_drivers.iter().enumerate().map(|(index, d)| {
-            let tie_offset = calculate_tie_offset(&filtered_drivers, index, |d1, d2| {
-                d1.total_points() == d2.total_points()
-            });
-
-            let mut driver_row = vec![
-                if (index - tie_offset) < trophy_count {
-                    "T".to_string()
-                } else {
-                    "".to_string()
-                },
-                format!("{}", index + 1 - tie_offset),
-                d.name().clone(),
-            ];
-            d.points()
-                .iter()
-                .for_each(|points| driver_row.push(format!("{points}")));
-            driver_row.push(format!("{}", d.total_points()));
-            driver_row.push(format!("{}", d.best_of(events_to_count)));
-            format!("\"{}\"", driver_row.join("\",\""))
-        }));
-
-        Ok(Some(rows.join("\n")))
+        let rows = filtered_drivers
+            .iter()
+            .enumerate()
+            .map(|(index, d)| {
+                let tie_offset = calculate_tie_offset(&filtered_drivers, index, |d1, d2| {
+                    d1.total_points() == d2.total_points()
+                });
+                ChampionshipRow {
+                    trophy: (index - tie_offset) < trophy_count,
+                    rank: index + 1 - tie_offset,
+                    driver: d.name().clone(),
+                    points: d.points().clone(),
+                    total_points: d.total_points(),
+                    best_of: d.best_of(events_to_count),
+                    visitor: false,
+                }
+            })
+            .collect();
+
+        Ok(Some(ChampionshipStandings {
+            championship_type,
+            organization: results.organization,
+            year: results.year,
+            event_count,
+            events_to_count,
+            event_labels: ChampionshipStandings::default_event_labels(event_count),
+            sections: vec![ChampionshipSection {
+                car_class: None,
+                label: None,
+                rows,
+            }],
+        }))
     }
 }
 
@@ -102,14 +99,6 @@ impl DefaultIndexedCsvBuilder {
             trophy_calculator: trophy_calculator.unwrap_or_else(|| Box::new(DefaultTrophyCalculator {})),
         }
     }
-
-    fn build_header(event_count: usize) -> String {
-        let mut header = vec!["Trophy".to_string(), "Rank".to_string(), "Driver".to_string()];
-        header.extend((0..event_count).map(|i| format!("Event #{}", i + 1)));
-        header.push("Total Points".to_string());
-        header.push(format!("Best {} of {}", events_to_count(event_count), event_count));
-        header.join(",")
-    }
 }
 
 #[cfg(test)]
@@ -151,7 +140,7 @@ mod test {
         let actual_option = actual.unwrap();
         assert!(actual_option.is_some());
 
-        let unwrapped = actual_option.unwrap();
+        let unwrapped = actual_option.unwrap().to_csv();
 
         assert_eq!(
             unwrapped,
@@ -159,10 +148,47 @@ mod test {
 2022 PAX Championship -- Best 1 of 1 Events\n\
 \n\
 Trophy,Rank,Driver,Event #1,Total Points,Best 1 of 1\n\
-T,1,Name 3,100,100,100\n\
-T,2,Name 2,10,10,10\n\
-T,2,Name 1,10,10,10"
+\"T\",\"1\",\"Name 3\",\"100\",\"100\",\"100\"\n\
+\"T\",\"2\",\"Name 2\",\"10\",\"10\",\"10\"\n\
+\"T\",\"2\",\"Name 1\",\"10\",\"10\",\"10\""
                 .to_string()
         );
     }
+
+    #[test]
+    fn test_typed_rows() {
+        let testable = DefaultIndexedCsvBuilder::from(Some(Box::from(MockTrophyCalculator {})));
+
+        let mut d1 = ChampionshipDriver::new("Name 1");
+        let mut d2 = ChampionshipDriver::new("Name 2");
+        let mut d3 = ChampionshipDriver::new("Name 3");
+
+        d1.add_event(10);
+        d2.add_event(50);
+        d3.add_event(100);
+
+        let actual = testable
+            .create(
+                ChampionshipType::Novice,
+                IndexedChampionshipResults::new(2022, "SCCA".to_string(), vec![d1, d2, d3]),
+            )
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(actual.championship_type, ChampionshipType::Novice);
+        assert_eq!(actual.events_to_count, 1);
+        assert_eq!(actual.sections.len(), 1);
+        assert_eq!(
+            actual.sections[0]
+                .rows
+                .iter()
+                .map(|row| (row.trophy, row.rank, row.driver.as_str(), row.best_of))
+                .collect::<Vec<(bool, usize, &str, i64)>>(),
+            vec![
+                (true, 1, "Name 3", 100),
+                (true, 2, "Name 2", 50),
+                (false, 3, "Name 1", 10)
+            ]
+        );
+    }
 }
@@ -1,12 +1,15 @@
 use crate::enums::championship_type::ChampionshipType;
+use crate::enums::long_car_class::to_display_name;
 use crate::enums::short_car_class::ShortCarClass;
-use crate::models::car_class::{get_car_class, CarClass};
+use crate::models::class_result_row::ClassResultRow;
 use crate::models::class_results::ClassResults;
 use crate::models::event_results::EventResults;
+use crate::models::result_time::ResultTime;
 use crate::services::calculators::championship_points_calculator::{
     ChampionshipPointsCalculator, DefaultChampionshipPointsCalculator,
 };
 use crate::services::calculators::trophy_calculator::{DefaultTrophyCalculator, TrophyCalculator};
+use crate::services::csv::builder::event::event_preamble;
 use csv::Writer;
 use wasm_bindgen::JsValue;
 
@@ -34,35 +37,49 @@ impl ClassResultsBuilder {
     }
 
     pub fn to_csvs(&self, results: &EventResults) -> Vec<JsValue> {
-        let mut results = results
-            .results
+        results
+            .sorted_class_results()
             .iter()
-            .map(|(class, results)| {
-                (
-                    get_car_class(class).unwrap_or_else(|| panic!("Missing class {} in class map", class.name())),
-                    results,
-                )
+            .map(|results| {
+                let class = results.car_class;
+                serde_wasm_bindgen::to_value(&(class, self.export_class(results)))
+                    .unwrap_or_else(|_| panic!("Failed to serialize class CSV for {}", class.long.name()))
             })
-            .collect::<Vec<(CarClass, &ClassResults)>>();
-
-        results.sort_by(|(lhs, ..), (rhs, ..)| {
-            if lhs.category == rhs.category {
-                lhs.short.cmp(&rhs.short)
-            } else {
-                lhs.category.cmp(&rhs.category)
-            }
-        });
+            .collect()
+    }
+
+    /// Every class in one export: the event's details, the header, then each class's name followed by its results.
+    /// [`ClassResultsBuilder::to_csvs()`] gives the classes' tables alone.
+    pub fn to_csv(&self, results: &EventResults) -> Result<String, String> {
+        let mut csv = event_preamble(&results.event)?;
+        csv.push_str(&self.get_header());
+        csv.push('\n');
+        for class_results in results.sorted_class_results() {
+            let class = class_results.car_class;
+            csv.push_str(&format!("{} - {}\n", class.short.name(), to_display_name(class.long)));
+            csv.push_str(&self.export_class(class_results));
+        }
+        Ok(csv)
+    }
 
+    /// Typed counterpart of [`ClassResultsBuilder::to_csvs()`]: each class paired with its rows
+    pub fn to_rows(&self, results: &EventResults) -> Vec<JsValue> {
         results
+            .sorted_class_results()
             .iter()
-            .map(|(class, results)| {
-                serde_wasm_bindgen::to_value(&(class, self.export_class(results)))
-                    .unwrap_or_else(|_| panic!("Failed to serialize class CSV for {}", class.long.name()))
+            .map(|results| {
+                let class = results.car_class;
+                serde_wasm_bindgen::to_value(&(class, self.build_rows(results)))
+                    .unwrap_or_else(|_| panic!("Failed to serialize class results for {}", class.long.name()))
             })
             .collect()
     }
 
     pub fn get_header(&self) -> String {
+        self.get_header_cells().join(",")
+    }
+
+    pub fn get_header_cells(&self) -> Vec<String> {
         vec![
             "Trophy".to_string(),
             "Pos".to_string(),
@@ -75,58 +92,96 @@ impl ClassResultsBuilder {
             "From Previous".to_string(),
             "From Top".to_string(),
             "Points".to_string(),
+            "Region".to_string(),
         ]
-        .join(",")
     }
 
     fn export_class(&self, class_results: &ClassResults) -> String {
-        let short_class_name = class_results.car_class.short.name().to_string();
+        let mut csv = Writer::from_writer(vec![]);
+        self.build_rows(class_results).iter().for_each(|row| {
+            csv.write_record(row.to_record())
+                .unwrap_or_else(|_| panic!("Failed to write record for {} to class results CSV", row.name));
+        });
+
+        String::from_utf8(csv.into_inner().unwrap()).unwrap()
+    }
+
+    /// One row per driver, in finishing order
+    pub fn build_rows(&self, class_results: &ClassResults) -> Vec<ClassResultRow> {
         let trophy_count = self
             .trophy_calculator
             .calculate(class_results.drivers.len(), Some(ChampionshipType::Class));
 
-        let mut csv = Writer::from_writer(vec![]);
-
         let best_lap_in_class = class_results.get_best_in_class();
 
-        class_results.drivers.iter().enumerate().for_each(|(i, d)| {
-            let compare_on_pro = class_results.car_class.short == ShortCarClass::P;
-            let best_lap = d.best_lap(compare_on_pro);
-            csv.write_record(vec![
-                if i < trophy_count {
-                    "T".to_string()
-                } else {
-                    "".to_string()
-                },
-                d.position.map(|p| format!("{p}")).unwrap_or_default(),
-                d.name.clone(),
-                d.car_description.clone(),
-                if compare_on_pro {
-                    d.pax_class.map(|c| c.short.name()).unwrap_or_default().to_string()
-                } else {
-                    short_class_name.clone()
-                },
-                format!("{}", d.car_number),
-                best_lap.to_string(false, false),
-                best_lap.to_string(true, false),
-                if i == 0 {
-                    "".to_string()
-                } else {
-                    d.difference(
-                        class_results.drivers.get(i - 1).unwrap().best_lap(compare_on_pro),
-                        true,
-                        compare_on_pro,
-                    )
-                },
-                d.difference(best_lap_in_class.clone(), true, compare_on_pro),
-                format!(
-                    "{}",
-                    self.points_calculator.calculate(&best_lap_in_class, d, compare_on_pro)
-                ),
-            ])
-            .unwrap_or_else(|_| panic!("Failed to write record for {} to class results CSV", d.name));
-        });
+        class_results
+            .drivers
+            .iter()
+            .enumerate()
+            .map(|(i, d)| {
+                let compare_on_pro = class_results.car_class.short == ShortCarClass::P;
+                let best_lap = d.best_lap(compare_on_pro);
+                ClassResultRow {
+                    trophy: i < trophy_count,
+                    position: d.position,
+                    name: d.name.clone(),
+                    car: d.car_description.clone(),
+                    car_class: if compare_on_pro {
+                        d.pax_class.map(|c| c.short)
+                    } else {
+                        Some(class_results.car_class.short)
+                    },
+                    car_number: d.car_number,
+                    co_drivers: d.co_drivers.clone(),
+                    total_time: ResultTime::new(best_lap.to_string(false, false)),
+                    index_time: ResultTime::new(best_lap.to_string(true, false)),
+                    from_previous: ResultTime::new(if i == 0 {
+                        "".to_string()
+                    } else {
+                        d.difference(
+                            class_results.drivers.get(i - 1).unwrap().best_lap(compare_on_pro),
+                            true,
+                            compare_on_pro,
+                        )
+                    }),
+                    from_top: ResultTime::new(d.difference(best_lap_in_class.clone(), true, compare_on_pro)),
+                    points: self.points_calculator.calculate(&best_lap_in_class, d, compare_on_pro),
+                    region: d.region.clone(),
+                }
+            })
+            .collect()
+    }
+}
 
-        String::from_utf8(csv.into_inner().unwrap()).unwrap()
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use crate::enums::class_source::ClassSource;
+    use crate::models::event::Event;
+    use crate::services::csv::builder::event::class_results_builder::ClassResultsBuilder;
+    use crate::services::csv::parser::event_results_parser::parse;
+
+    #[test]
+    fn exports_every_class_below_the_event() {
+        let sample_contents = fs::read_to_string("./SampleData/2022/2022_Event1-DavidExport.csv").unwrap();
+        let results = parse(
+            None,
+            sample_contents,
+            ClassSource::Pronto,
+            Event::new("Spring Opener".to_string()),
+        )
+        .unwrap();
+        let builder = ClassResultsBuilder::default();
+
+        let actual = builder.to_csv(&results).unwrap();
+        let lines = actual.lines().collect::<Vec<&str>>();
+
+        assert_eq!(lines[0], "Spring Opener");
+        assert_eq!(lines[1], "");
+        assert_eq!(lines[2], builder.get_header());
+        assert_eq!(lines[3], "AS - A Street");
+        assert!(lines[4].contains("Robert Fullriede"));
+        assert!(lines.contains(&"BS - B Street"));
     }
 }
@@ -2,14 +2,17 @@ use crate::enums::championship_type::ChampionshipType;
 use csv::Writer;
 
 use crate::enums::driver_group::DriverGroup;
+use crate::models::combined_result_row::CombinedResultRow;
 use crate::models::driver::Driver;
 use crate::models::event_results::EventResults;
 use crate::models::lap_time::LapTime;
+use crate::models::result_time::ResultTime;
 use crate::services::calculators::championship_points_calculator::{
     ChampionshipPointsCalculator, DefaultChampionshipPointsCalculator,
 };
 use crate::services::calculators::tie_calculator::calculate_tie_offset;
 use crate::services::calculators::trophy_calculator::{DefaultTrophyCalculator, TrophyCalculator};
+use crate::services::csv::builder::event::event_preamble;
 
 /// Build indexed and raw time CSV results for a single event
 pub struct CombinedResultsBuilder {
@@ -25,15 +28,35 @@ impl Default for CombinedResultsBuilder {
 
 impl CombinedResultsBuilder {
     pub fn to_combined_csv(&self, results: &EventResults, driver_group: DriverGroup) -> Result<String, String> {
+        let rows = self.to_combined_rows(results, driver_group)?;
+        if rows.is_empty() {
+            Ok(format!("No drivers for {} group", driver_group.name()))
+        } else {
+            let mut csv = Writer::from_writer(vec![]);
+            csv.write_record(self.get_combined_header(driver_group == DriverGroup::Raw))
+                .map_err(|e| e.to_string())?;
+            for row in rows {
+                csv.write_record(row.to_record()).map_err(|e| e.to_string())?;
+            }
+            let csv_byte_array = csv.into_inner().map_err(|e| e.to_string())?;
+            let table = String::from_utf8(csv_byte_array).map_err(|e| e.to_string())?;
+            Ok(event_preamble(&results.event)? + &table)
+        }
+    }
+
+    /// One row per driver in the group, fastest first
+    pub fn to_combined_rows(
+        &self,
+        results: &EventResults,
+        driver_group: DriverGroup,
+    ) -> Result<Vec<CombinedResultRow>, String> {
         let is_raw_time = driver_group == DriverGroup::Raw;
 
         let drivers = results.get_drivers(Some(driver_group));
         if drivers.is_empty() {
-            Ok(format!("No drivers for {} group", driver_group.name()))
+            Ok(vec![])
         } else {
-            let csv = self.build_csv(drivers, driver_group, is_raw_time)?;
-            let csv_byte_array = csv.into_inner().map_err(|e| e.to_string())?;
-            String::from_utf8(csv_byte_array).map_err(|e| e.to_string())
+            self.build_rows(drivers, driver_group, is_raw_time)
         }
     }
 
@@ -47,7 +70,7 @@ impl CombinedResultsBuilder {
         }
     }
 
-    fn get_combined_header(&self, is_raw_time: bool) -> Vec<String> {
+    pub fn get_combined_header(&self, is_raw_time: bool) -> Vec<String> {
         let mut time_column = (if is_raw_time { "Best" } else { "Index" }).to_string();
         time_column.push_str(" Time");
         let mut header = vec![
@@ -64,15 +87,16 @@ impl CombinedResultsBuilder {
         if !is_raw_time {
             header.push("Points".to_string());
         }
+        header.push("Region".to_string());
         header
     }
 
-    fn build_csv(
+    fn build_rows(
         &self,
         drivers: Vec<&Driver>,
         driver_group: DriverGroup,
         is_raw_time: bool,
-    ) -> Result<Writer<Vec<u8>>, String> {
+    ) -> Result<Vec<CombinedResultRow>, String> {
         let fastest_driver = drivers.first().unwrap();
         let fastest_of_day = fastest_driver.best_lap(false);
 
@@ -81,9 +105,7 @@ impl CombinedResultsBuilder {
             .trophy_calculator
             .calculate(driver_count, ChampionshipType::from(driver_group));
 
-        let mut csv = Writer::from_writer(vec![]);
-        csv.write_record(self.get_combined_header(is_raw_time))
-            .map_err(|e| e.to_string())?;
+        let mut rows = vec![];
 
         for i in 0..driver_count {
             let next_row = self.build_record(
@@ -94,10 +116,10 @@ impl CombinedResultsBuilder {
                 is_raw_time,
                 fastest_of_day.clone(),
             )?;
-            csv.write_record(next_row).map_err(|e| e.to_string())?;
+            rows.push(next_row);
         }
 
-        Ok(csv)
+        Ok(rows)
     }
 
     fn build_record(
@@ -108,38 +130,34 @@ impl CombinedResultsBuilder {
         trophy_count: usize,
         is_raw_time: bool,
         fastest_of_day: LapTime,
-    ) -> Result<Vec<String>, String> {
-        let previous_driver = drivers.get(i - 1);
+    ) -> Result<CombinedResultRow, String> {
+        let previous_driver = i.checked_sub(1).and_then(|prev| drivers.get(prev));
         let driver = drivers
             .get(i)
             .ok_or(format!("expected at least one driver for {}", driver_group.name()))?;
 
         let tie_offset = calculate_tie_offset(drivers, i, |d1, d2| d1.best_lap(false) == d2.best_lap(false));
 
-        let mut next_row = vec![
-            if (i - tie_offset) < trophy_count {
-                "T".to_string()
+        Ok(CombinedResultRow {
+            trophy: (i - tie_offset) < trophy_count,
+            position: i + 1 - tie_offset,
+            name: driver.name.clone(),
+            car: driver.car_description.clone(),
+            car_class: driver.car_class.short,
+            car_number: driver.car_number,
+            time: ResultTime::new(driver.best_lap(false).to_string(!is_raw_time, false)),
+            from_previous: ResultTime::new(
+                previous_driver
+                    .map(|prev| driver.difference(prev.best_lap(false), !is_raw_time, false))
+                    .unwrap_or_default(),
+            ),
+            from_top: ResultTime::new(driver.difference(fastest_of_day.clone(), !is_raw_time, false)),
+            points: if is_raw_time {
+                None
             } else {
-                "".to_string()
+                Some(self.points_calculator.calculate(&fastest_of_day, driver, false))
             },
-            format!("{}", i + 1 - tie_offset),
-            driver.name.clone(),
-            driver.car_description.clone(),
-            driver.car_class.short.name().to_string(),
-            format!("{}", driver.car_number),
-            driver.best_lap(false).to_string(!is_raw_time, false),
-            previous_driver
-                .map(|prev| driver.difference(prev.best_lap(false), !is_raw_time, false))
-                .unwrap_or_default(),
-            driver.difference(fastest_of_day.clone(), !is_raw_time, false),
-        ];
-        if !is_raw_time {
-            next_row.push(format!(
-                "{}",
-                self.points_calculator.calculate(&fastest_of_day, driver, false)
-            ))
-        }
-
-        Ok(next_row)
+            region: driver.region.clone(),
+        })
     }
 }
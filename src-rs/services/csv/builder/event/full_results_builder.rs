@@ -0,0 +1,142 @@
+use csv::Writer;
+
+use crate::enums::short_car_class::ShortCarClass;
+use crate::models::class_results::ClassResults;
+use crate::models::driver::Driver;
+use crate::models::event_results::EventResults;
+use crate::services::csv::builder::event::event_preamble;
+
+/// Build run-by-run results for a single event, listing every run of every driver grouped by class
+#[derive(Default)]
+pub struct FullResultsBuilder {}
+
+impl FullResultsBuilder {
+    pub fn get_header(&self) -> Vec<String> {
+        vec![
+            "Class".to_string(),
+            "Pos".to_string(),
+            "Name".to_string(),
+            "Number".to_string(),
+            "Car".to_string(),
+            "Run".to_string(),
+            "Raw Time".to_string(),
+            "Cones".to_string(),
+            "Penalty".to_string(),
+            "Adjusted Time".to_string(),
+            "Best".to_string(),
+        ]
+    }
+
+    pub fn to_csv(&self, results: &EventResults) -> Result<String, String> {
+        let mut csv = Writer::from_writer(vec![]);
+        csv.write_record(self.get_header()).map_err(|e| e.to_string())?;
+
+        for class_results in results.sorted_class_results() {
+            for row in self.build_class_rows(class_results) {
+                csv.write_record(row).map_err(|e| e.to_string())?;
+            }
+        }
+
+        let table = String::from_utf8(csv.into_inner().map_err(|e| e.to_string())?).map_err(|e| e.to_string())?;
+        Ok(event_preamble(&results.event)? + &table)
+    }
+
+    fn build_class_rows(&self, class_results: &ClassResults) -> Vec<Vec<String>> {
+        let pro = class_results.car_class.short == ShortCarClass::P;
+        class_results
+            .drivers
+            .iter()
+            .flat_map(|driver| self.build_driver_rows(driver, pro))
+            .collect()
+    }
+
+    fn build_driver_rows(&self, driver: &Driver, pro: bool) -> Vec<Vec<String>> {
+        let best_lap_index = driver.best_lap_index(pro);
+        driver
+            .times
+            .iter()
+            .enumerate()
+            .map(|(index, lap)| {
+                vec![
+                    driver.car_class.short.name().to_string(),
+                    driver.position.map(|p| format!("{p}")).unwrap_or_default(),
+                    driver.name.clone(),
+                    format!("{}", driver.car_number),
+                    driver.car_description.clone(),
+                    format!("{}", index + 1),
+                    lap.raw
+                        .as_ref()
+                        .map(|raw| format!("{:.3}", raw.round(3)))
+                        .unwrap_or_default(),
+                    format!("{}", lap.cones),
+                    lap.penalty().map(|p| p.name().to_string()).unwrap_or_default(),
+                    lap.to_string(false, false),
+                    if best_lap_index == Some(index) {
+                        "*".to_string()
+                    } else {
+                        "".to_string()
+                    },
+                ]
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use crate::enums::class_source::ClassSource;
+    use crate::models::event::Event;
+    use crate::services::csv::builder::event::full_results_builder::FullResultsBuilder;
+    use crate::services::csv::parser::event_results_parser::parse;
+
+    #[test]
+    fn lists_every_run() {
+        let sample_contents = fs::read_to_string("./SampleData/2022/2022_Event1-DavidExport.csv").unwrap();
+        let results = parse(None, sample_contents, ClassSource::Pronto, Event::default()).unwrap();
+
+        let actual = FullResultsBuilder::default().to_csv(&results).unwrap();
+        let lines = actual.lines().collect::<Vec<&str>>();
+
+        assert_eq!(
+            lines[0],
+            "Class,Pos,Name,Number,Car,Run,Raw Time,Cones,Penalty,Adjusted Time,Best"
+        );
+        assert_eq!(
+            lines[1],
+            "AS,1,Robert Fullriede,52,2010 Porsche Cayman,1,53.351,0,,53.351,"
+        );
+        assert_eq!(lines[2], "AS,1,Robert Fullriede,52,2010 Porsche Cayman,2,,0,DNF,DNF,");
+        assert_eq!(
+            lines[3],
+            "AS,1,Robert Fullriede,52,2010 Porsche Cayman,3,52.288,0,,52.288,*"
+        );
+        assert!(lines[4].starts_with("AS,2,Jeffrey Osborn,77,"));
+        assert!(lines.last().unwrap().starts_with("BS,1,Adam Buffa,32,"));
+    }
+
+    #[test]
+    fn describes_the_event_above_the_runs() {
+        let sample_contents = fs::read_to_string("./SampleData/2022/2022_Event1-DavidExport.csv").unwrap();
+        let event = Event {
+            event_number: Some(1),
+            date: Some("2022-04-10".to_string()),
+            ..Event::new("Spring Opener".to_string())
+        };
+        let results = parse(None, sample_contents, ClassSource::Pronto, event).unwrap();
+
+        let actual = FullResultsBuilder::default().to_csv(&results).unwrap();
+        let lines = actual.lines().take(4).collect::<Vec<&str>>();
+
+        assert_eq!(
+            lines,
+            vec![
+                "Spring Opener",
+                "Event #1 | 2022-04-10",
+                "",
+                "Class,Pos,Name,Number,Car,Run,Raw Time,Cones,Penalty,Adjusted Time,Best"
+            ]
+        );
+    }
+}
@@ -1,2 +1,23 @@
+use csv::Writer;
+
+use crate::models::event::Event;
+
 pub(crate) mod class_results_builder;
 pub(crate) mod combined_results_builder;
+pub(crate) mod full_results_builder;
+pub(crate) mod series_submission_builder;
+
+/// The event's header lines for the top of an event CSV, set apart from the table by a blank line, as championship
+/// standings set apart their title. An event that was never described gets none, leaving the table as the whole file.
+pub(crate) fn event_preamble(event: &Event) -> Result<String, String> {
+    if *event == Event::default() {
+        return Ok(String::new());
+    }
+    let mut csv = Writer::from_writer(vec![]);
+    for line in event.header_lines() {
+        csv.write_record([line]).map_err(|e| e.to_string())?;
+    }
+    let mut preamble = String::from_utf8(csv.into_inner().map_err(|e| e.to_string())?).map_err(|e| e.to_string())?;
+    preamble.push('\n');
+    Ok(preamble)
+}
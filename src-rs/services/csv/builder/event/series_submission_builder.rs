@@ -0,0 +1,175 @@
+use csv::Writer;
+
+use crate::enums::short_car_class::ShortCarClass;
+use crate::models::event_results::EventResults;
+use crate::models::series_definition::SeriesDefinition;
+use crate::models::series_result_row::SeriesResultRow;
+use crate::services::calculators::championship_points_calculator::{
+    ChampionshipPointsCalculator, DefaultChampionshipPointsCalculator,
+};
+
+/// Build a series' submission CSV from a single event: every finisher in the series' classes, with their class
+/// position and series points
+pub struct SeriesSubmissionBuilder {
+    points_calculator: Box<dyn ChampionshipPointsCalculator>,
+}
+
+impl Default for SeriesSubmissionBuilder {
+    fn default() -> Self {
+        SeriesSubmissionBuilder::from(None)
+    }
+}
+
+impl SeriesSubmissionBuilder {
+    pub fn from(points_calculator: Option<Box<dyn ChampionshipPointsCalculator>>) -> SeriesSubmissionBuilder {
+        SeriesSubmissionBuilder {
+            points_calculator: points_calculator.unwrap_or_else(|| Box::new(DefaultChampionshipPointsCalculator {})),
+        }
+    }
+
+    pub fn to_csv(&self, results: &EventResults, series: &SeriesDefinition) -> Result<String, String> {
+        let mut csv = Writer::from_writer(vec![]);
+        csv.write_record(SeriesResultRow::header()).map_err(|e| e.to_string())?;
+        for row in self.build_rows(results, series)? {
+            csv.write_record(row.to_record()).map_err(|e| e.to_string())?;
+        }
+        let csv_byte_array = csv.into_inner().map_err(|e| e.to_string())?;
+        String::from_utf8(csv_byte_array).map_err(|e| e.to_string())
+    }
+
+    /// One row per finisher, by class and then position. Disqualified drivers and drivers without a timed run are left
+    /// out, as are non-members when only members are eligible, and positions are recalculated among those remaining.
+    pub fn build_rows(
+        &self,
+        results: &EventResults,
+        series: &SeriesDefinition,
+    ) -> Result<Vec<SeriesResultRow>, String> {
+        let car_classes = series.car_classes()?;
+        let eligible = results.without(|d| {
+            d.dsq
+                || d.best_lap(d.car_class.short == ShortCarClass::P).time.is_none()
+                || (series.members_only && (d.non_member || d.member_number.trim().is_empty()))
+        });
+
+        Ok(eligible
+            .sorted_class_results()
+            .iter()
+            .filter(|class_results| car_classes.contains(&class_results.car_class.short))
+            .flat_map(|class_results| {
+                let pro = class_results.car_class.short == ShortCarClass::P;
+                let best_in_class = class_results.get_best_in_class();
+                class_results.drivers.iter().filter_map(move |d| {
+                    d.position.map(|position| SeriesResultRow {
+                        series: series.name.clone(),
+                        event: results.event.title(),
+                        date: results.event.date.clone().unwrap_or_default(),
+                        region: d.region.clone(),
+                        car_class: class_results.car_class.short,
+                        position,
+                        driver: d.name.clone(),
+                        member_number: d.member_number.clone(),
+                        car_number: d.car_number,
+                        car: d.car_description.clone(),
+                        time: d.best_lap(pro).to_string(false, false),
+                        points: series
+                            .table_points(position)
+                            .unwrap_or_else(|| self.points_calculator.calculate(&best_in_class, d, pro)),
+                    })
+                })
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use crate::enums::short_car_class::ShortCarClass;
+    use crate::models::event::Event;
+    use crate::models::event_results::EventResults;
+    use crate::models::series_definition::SeriesDefinition;
+    use crate::services::csv::builder::event::series_submission_builder::SeriesSubmissionBuilder;
+    use crate::services::csv::parser::event_results_parser::sample_event_with_msr;
+
+    fn series(points_table: Vec<i64>, members_only: bool) -> SeriesDefinition {
+        SeriesDefinition::new(
+            "Divisional Series".to_string(),
+            vec!["as".to_string()],
+            points_table,
+            members_only,
+        )
+    }
+
+    fn sample_results() -> EventResults {
+        let event = Event {
+            date: Some("2022-04-10".to_string()),
+            ..Event::new("Event 1".to_string())
+        };
+        sample_event_with_msr(["", "2", "3"], ["KC", "STL", "STL"], event)
+    }
+
+    fn builder_output(series: &SeriesDefinition) -> String {
+        SeriesSubmissionBuilder::default()
+            .to_csv(&sample_results(), series)
+            .unwrap()
+    }
+
+    #[test]
+    fn scores_eligible_classes_from_the_points_table() {
+        assert_eq!(
+            builder_output(&series(vec![20, 17], false)),
+            "Series,Event,Date,Region,Class,Position,Driver,Member #,Car #,Car,Time,Points\n\
+Divisional Series,Event 1,2022-04-10,KC,AS,1,Robert Fullriede,,52,2010 Porsche Cayman,52.288,20\n\
+Divisional Series,Event 1,2022-04-10,STL,AS,2,Jeffrey Osborn,2,77,2011 Chevrolet Corvette Grand Sport,54.187,17\n"
+        );
+    }
+
+    #[test]
+    fn scores_members_on_time_without_a_points_table() {
+        assert_eq!(
+            builder_output(&series(vec![], true)),
+            "Series,Event,Date,Region,Class,Position,Driver,Member #,Car #,Car,Time,Points\n\
+Divisional Series,Event 1,2022-04-10,STL,AS,1,Jeffrey Osborn,2,77,2011 Chevrolet Corvette Grand Sport,54.187,10000\n"
+        );
+    }
+
+    #[test]
+    fn leaves_out_disqualified_drivers() {
+        let mut results = sample_results();
+        results
+            .results
+            .get_mut(&ShortCarClass::AS)
+            .unwrap()
+            .drivers
+            .iter_mut()
+            .find(|d| d.name == "Robert Fullriede")
+            .unwrap()
+            .dsq = true;
+
+        assert_eq!(
+            SeriesSubmissionBuilder::default()
+                .to_csv(&results, &series(vec![20, 17], false))
+                .unwrap(),
+            "Series,Event,Date,Region,Class,Position,Driver,Member #,Car #,Car,Time,Points\n\
+Divisional Series,Event 1,2022-04-10,STL,AS,1,Jeffrey Osborn,2,77,2011 Chevrolet Corvette Grand Sport,54.187,20\n"
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_classes() {
+        let series = SeriesDefinition::new("Bogus".to_string(), vec!["XYZ".to_string()], vec![], false);
+        assert_eq!(
+            SeriesSubmissionBuilder::default().build_rows(
+                &EventResults {
+                    event: Default::default(),
+                    results: HashMap::new(),
+                    discrepancies: vec![],
+                    no_shows: vec![],
+                },
+                &series
+            ),
+            Err("Unknown class XYZ in Bogus".to_string())
+        );
+    }
+}
@@ -9,9 +9,11 @@ use regex::Regex;
 use crate::console_log;
 use crate::enums::championship_type::ChampionshipType;
 use crate::enums::short_car_class::ShortCarClass;
+use crate::models::championship_standings::ChampionshipStandings;
 use crate::models::driver::Driver;
 use crate::models::event_results::EventResults;
 use crate::models::lap_time::{dns, LapTime};
+use crate::models::organization_rules::OrganizationRules;
 use crate::models::type_aliases::DriverId;
 use crate::services::csv::builder::championship::class_csv_builder::{ClassCsvBuilder, DefaultClassCsvBuilder};
 use crate::services::csv::builder::championship::indexed_csv_builder::{DefaultIndexedCsvBuilder, IndexedCsvBuilder};
@@ -21,7 +23,6 @@ use crate::services::csv::parser::class_championship_results_parser::{
 use crate::services::csv::parser::index_championship_results_parser::{
     DefaultIndexChampionshipResultsParser, IndexChampionshipResultsParser,
 };
-use crate::utilities::log;
 
 pub struct ChampionshipResultsParser {
     class_results_parser: Rc<RefCell<dyn ClassChampionshipResultsParser>>,
@@ -30,6 +31,8 @@ pub struct ChampionshipResultsParser {
     indexed_csv_builder: Rc<RefCell<dyn IndexedCsvBuilder>>,
 
     event_results: EventResults,
+    /// When set, prior standings must be for this organization
+    organization: Option<String>,
 }
 
 impl ChampionshipResultsParser {
@@ -40,6 +43,27 @@ impl ChampionshipResultsParser {
             class_csv_builder: Rc::new(RefCell::new(DefaultClassCsvBuilder::default())),
             indexed_csv_builder: Rc::new(RefCell::new(DefaultIndexedCsvBuilder::default())),
             event_results,
+            organization: None,
+        }
+    }
+
+    /// Scored under an organization's own points and trophy rules rather than the defaults
+    pub fn from(event_results: EventResults, rules: &OrganizationRules) -> ChampionshipResultsParser {
+        ChampionshipResultsParser {
+            class_results_parser: Rc::new(RefCell::new(DefaultClassChampionshipResultsParser::from(Some(
+                rules.points_calculator(),
+            )))),
+            index_results_parser: Rc::new(RefCell::new(DefaultIndexChampionshipResultsParser::from(Some(
+                rules.points_calculator(),
+            )))),
+            class_csv_builder: Rc::new(RefCell::new(DefaultClassCsvBuilder::from(Some(
+                rules.trophy_calculator(),
+            )))),
+            indexed_csv_builder: Rc::new(RefCell::new(DefaultIndexedCsvBuilder::from(Some(
+                rules.trophy_calculator(),
+            )))),
+            event_results,
+            organization: Some(rules.organization.clone()),
         }
     }
 
@@ -49,6 +73,20 @@ impl ChampionshipResultsParser {
         new_results: &[u8],
         file_name: String,
     ) -> Result<String, String> {
+        self.process_standings(new_results_type, new_results, file_name)
+            .map(|standings| match standings {
+                Some(standings) => standings.to_csv(),
+                None => format!("No results for {}", new_results_type.name()),
+            })
+    }
+
+    /// Combine prior championship results with the current event, returning `None` if nobody has scored
+    pub fn process_standings(
+        &self,
+        new_results_type: ChampionshipType,
+        new_results: &[u8],
+        file_name: String,
+    ) -> Result<Option<ChampionshipStandings>, String> {
         let event_drivers_by_id = self
             .event_results
             .get_drivers(None)
@@ -58,10 +96,13 @@ impl ChampionshipResultsParser {
             .collect::<HashMap<DriverId, &Driver>>();
 
         let old_data = self.extract_sheet(file_name, new_results)?;
-        let header_map = self.get_header_map(&old_data)?;
-        let past_event_count = self.get_past_event_count(&header_map)?;
+        self.check_organization(&old_data)?;
+        let header_row = self.get_header_row(&old_data)?;
+        let past_event_labels = Self::get_past_event_labels(&header_row)?;
+        let past_event_count = past_event_labels.len();
+        let header_map = Self::get_header_map(&header_row);
 
-        let result = if new_results_type == ChampionshipType::Class {
+        let standings = if new_results_type == ChampionshipType::Class {
             self.class_csv_builder
                 .borrow()
                 .create(self.class_results_parser.borrow().parse(
@@ -89,14 +130,40 @@ impl ChampionshipResultsParser {
                 &fastest,
             )?;
             self.indexed_csv_builder.borrow().create(new_results_type, results)
-        };
+        }?;
 
-        result.map(|csv_option| csv_option.unwrap_or(format!("No results for {}", new_results_type.name())))
+        Ok(standings.map(|mut standings| {
+            standings.label_past_events(&past_event_labels);
+            if let Some(label) = self.event_results.event.column_label() {
+                standings.label_latest_event(label);
+            }
+            standings.flag_visitors(
+                &event_drivers_by_id
+                    .values()
+                    .filter(|d| d.visitor)
+                    .map(|d| d.name.clone())
+                    .collect::<Vec<String>>(),
+            );
+            standings
+        }))
+    }
+
+    /// Catch another organization's standings uploaded by mistake, which would otherwise be mixed with this event
+    fn check_organization(&self, data: &Range<Data>) -> Result<(), String> {
+        let Some(expected) = &self.organization else {
+            return Ok(());
+        };
+        let found = data.get((0, 0)).map(|cell| cell.to_string()).unwrap_or_default();
+        if found.trim().eq_ignore_ascii_case(expected.trim()) {
+            Ok(())
+        } else {
+            Err(format!("Prior standings are for '{}', not {expected}", found.trim()))
+        }
     }
 
     fn extract_sheet(&self, file_name: String, new_results: &[u8]) -> Result<Range<Data>, String> {
         let new_results = Cursor::new(new_results);
-        let mut workbook = Xls::new(new_results).map_err(|e| format!("{}", e))?;
+        let mut workbook = Xls::new(new_results).map_err(|e| format!("{e}"))?;
         let worksheets = workbook.worksheets();
         let mut sheets = worksheets
             .iter()
@@ -117,14 +184,16 @@ impl ChampionshipResultsParser {
             console_log!("Found sheet with name {}", sheet_name);
             Ok(sheet_data.clone())
         } else if sheets.len() > 1 {
-            log(format!("Sheet '{}' doesn't have enough rows, checking next", sheet_name).as_str());
+            console_log!("Sheet '{}' doesn't have enough rows, checking next", sheet_name);
             Self::find_sheet(file_name, &sheets[1..])
         } else {
-            Err(format!("File {} contains no non-empty sheets", file_name))
+            Err(format!("File {file_name} contains no non-empty sheets"))
         }
     }
 
-    fn get_header_map(&self, data: &Range<Data>) -> Result<HashMap<String, usize>, String> {
+    /// The header row of the prior standings, with runs of whitespace (such as the line breaks in `Total\nPoints`)
+    /// collapsed to a single space
+    fn get_header_row(&self, data: &Range<Data>) -> Result<Vec<String>, String> {
         let re = Regex::new(r"^\s*best\s+\d+\s+of\s+\d+\s*$").map_err(|e| e.to_string())?;
         Ok(data
             .rows()
@@ -134,19 +203,58 @@ impl ChampionshipResultsParser {
             })
             .ok_or_else(|| "Unable to find header".to_string())?
             .iter()
-            .enumerate()
-            .map(|(index, header)| (header.to_string(), index))
+            .map(|header| header.to_string().split_whitespace().collect::<Vec<&str>>().join(" "))
             .collect())
     }
 
-    fn get_past_event_count(&self, header_map: &HashMap<String, usize>) -> Result<usize, String> {
-        let re =
-            Regex::new(r"^(Trophy|Rank|Driver|Total\s+Points|Best\s+\d+\s+of\s+\d+)$").map_err(|e| e.to_string())?;
-        Ok(header_map.keys().filter(|header| !re.is_match(header)).count())
+    fn get_header_map(header_row: &[String]) -> HashMap<String, usize> {
+        header_row
+            .iter()
+            .enumerate()
+            .map(|(index, header)| (header.clone(), index))
+            .collect()
+    }
+
+    /// Labels of the events already in the standings, which are every column between `Driver` and `Total Points`.
+    /// The count is checked against the closing `Best X of Y` column so that a misaligned sheet is rejected instead of
+    /// being scored against the wrong events.
+    fn get_past_event_labels(header_row: &[String]) -> Result<Vec<String>, String> {
+        let position = |name: &str| {
+            header_row
+                .iter()
+                .position(|header| header.eq_ignore_ascii_case(name))
+                .ok_or_else(|| format!("Missing '{name}' column"))
+        };
+        let driver_index = position("Driver")?;
+        let total_points_index = position("Total Points")?;
+        if total_points_index <= driver_index {
+            return Err("Expected event columns between 'Driver' and 'Total Points'".to_string());
+        }
+
+        let labels = header_row[driver_index + 1..total_points_index].to_vec();
+        if let Some(index) = labels.iter().position(|label| label.is_empty()) {
+            return Err(format!(
+                "Event column {} has no header; columns may be misaligned",
+                index + 1
+            ));
+        }
+
+        let re = Regex::new(r"(?i)^best\s+\d+\s+of\s+(\d+)$").map_err(|e| e.to_string())?;
+        let expected = header_row
+            .last()
+            .and_then(|header| re.captures(header))
+            .and_then(|captures| captures[1].parse::<usize>().ok());
+        match expected {
+            Some(expected) if expected != labels.len() => Err(format!(
+                "Found {} event columns, but the standings are for {expected} events; columns may be misaligned",
+                labels.len()
+            )),
+            _ => Ok(labels),
+        }
     }
 
     fn compute_fastest(drivers: &HashMap<DriverId, &Driver>) -> LapTime {
-        drivers.iter().map(|(_, d)| d.best_lap(false)).min().unwrap_or_else(dns)
+        drivers.values().map(|d| d.best_lap(false)).min().unwrap_or_else(dns)
     }
 }
 
@@ -154,11 +262,17 @@ impl ChampionshipResultsParser {
 mod test {
     use std::cell::RefCell;
     use std::collections::HashMap;
+    use std::fs;
     use std::rc::Rc;
 
+    use rstest::rstest;
+
+    use crate::models::event::Event;
+    use crate::models::organization_rules::OrganizationRules;
     use crate::services::csv::builder::championship::class_csv_builder::MockClassCsvBuilder;
     use crate::services::csv::builder::championship::indexed_csv_builder::MockIndexedCsvBuilder;
     use crate::services::csv::parser::class_championship_results_parser::MockClassChampionshipResultsParser;
+    use crate::services::csv::parser::event_results_parser::sample_event_with_msr;
     use crate::services::csv::parser::index_championship_results_parser::MockIndexChampionshipResultsParser;
     use crate::{ChampionshipResultsParser, ChampionshipType, EventResults};
 
@@ -188,6 +302,7 @@ mod test {
                     class_csv_builder: mock_class_csv_builder,
                     indexed_csv_builder: mock_indexed_csv_builder,
                     event_results,
+                    organization: None,
                 },
             }
         }
@@ -196,7 +311,10 @@ mod test {
     #[test]
     fn test_process_results_bad_excel_fails_gracefully() {
         let results = EventResults {
+            event: Default::default(),
             results: HashMap::new(),
+            discrepancies: vec![],
+            no_shows: vec![],
         };
         let context = Context::new(results);
         {
@@ -215,4 +333,106 @@ mod test {
             Err("Cfb error: I/O error: failed to fill whole buffer".to_string())
         );
     }
+
+    fn header(cells: &[&str]) -> Vec<String> {
+        cells.iter().map(|cell| cell.to_string()).collect()
+    }
+
+    #[rstest]
+    #[case("StL_PAX_thru_event5.xls", 5)]
+    #[case("StL_Class_thru_event2.xls", 2)]
+    #[case("StL_Ladies_thru_event6.xls", 6)]
+    fn reads_past_event_labels_from_sample_sheets(#[case] file_name: &str, #[case] expected: usize) {
+        let context = Context::new(EventResults {
+            event: Default::default(),
+            results: HashMap::new(),
+            discrepancies: vec![],
+            no_shows: vec![],
+        });
+        let bytes = fs::read(format!("./SampleData/{file_name}")).unwrap();
+
+        let sheet = context.testable.extract_sheet(file_name.to_string(), &bytes).unwrap();
+        let header_row = context.testable.get_header_row(&sheet).unwrap();
+
+        assert_eq!(
+            ChampionshipResultsParser::get_past_event_labels(&header_row).map(|labels| labels.len()),
+            Ok(expected)
+        );
+    }
+
+    #[test]
+    fn reads_labelled_event_columns() {
+        let actual = ChampionshipResultsParser::get_past_event_labels(&header(&[
+            "Trophy",
+            "Rank",
+            "Driver",
+            "Spring Opener (2024-04-21)",
+            "Event #2",
+            "Total Points",
+            "Best 2 of 2",
+        ]));
+        assert_eq!(actual, Ok(header(&["Spring Opener (2024-04-21)", "Event #2"])));
+    }
+
+    #[rstest]
+    #[case(
+        &["Trophy", "Rank", "Driver", "Event #1", "Total Points", "Best 2 of 2"],
+        "Found 1 event columns, but the standings are for 2 events; columns may be misaligned"
+    )]
+    #[case(
+        &["Trophy", "Rank", "Driver", "Event #1", "", "Total Points", "Best 2 of 2"],
+        "Event column 2 has no header; columns may be misaligned"
+    )]
+    #[case(&["Trophy", "Rank", "Event #1", "Total Points", "Best 1 of 1"], "Missing 'Driver' column")]
+    fn rejects_misaligned_columns(#[case] cells: &[&str], #[case] expected: &str) {
+        assert_eq!(
+            ChampionshipResultsParser::get_past_event_labels(&header(cells)),
+            Err(expected.to_string())
+        );
+    }
+
+    #[test]
+    fn scores_only_the_organizations_drivers_under_its_rules() {
+        let results = sample_event_with_msr(["1", "2", "3"], ["KC", "STL", "STL"], Event::default());
+        let rules = OrganizationRules::new("St. Louis Region SCCA".to_string(), vec!["stl".to_string()], 100, 1, 1);
+        let home = results.without(|d| !rules.scores(d));
+        let bytes = fs::read("./SampleData/2022/2022_StL_PAX_thru_event1.xls").unwrap();
+
+        let standings = ChampionshipResultsParser::from(home, &rules)
+            .process_standings(
+                ChampionshipType::PAX,
+                &bytes,
+                "2022_StL_PAX_thru_event1.xls".to_string(),
+            )
+            .unwrap()
+            .unwrap();
+
+        let rows = &standings.sections[0].rows;
+        let latest = |name: &str| {
+            rows.iter()
+                .find(|row| row.driver == name)
+                .map(|row| *row.points.last().unwrap())
+        };
+        assert_eq!(latest("Jeffrey Osborn"), Some(100));
+        assert_eq!(latest("Adam Buffa"), Some(96));
+        assert_eq!(latest("Robert Fullriede"), None);
+    }
+
+    #[test]
+    fn rejects_standings_from_another_organization() {
+        let results = sample_event_with_msr(["1", "2", "3"], ["KC", "STL", "STL"], Event::default());
+        let rules = OrganizationRules::new("Kansas City Region SCCA".to_string(), vec!["kc".to_string()], 100, 1, 1);
+        let bytes = fs::read("./SampleData/2022/2022_StL_PAX_thru_event1.xls").unwrap();
+
+        let result = ChampionshipResultsParser::from(results, &rules).process_standings(
+            ChampionshipType::PAX,
+            &bytes,
+            "2022_StL_PAX_thru_event1.xls".to_string(),
+        );
+
+        assert_eq!(
+            result.err(),
+            Some("Prior standings are for 'St. Louis Region SCCA', not Kansas City Region SCCA".to_string())
+        );
+    }
 }
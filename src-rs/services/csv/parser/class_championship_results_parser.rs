@@ -74,13 +74,19 @@ impl ClassChampionshipResultsParser for DefaultClassChampionshipResultsParser {
 
 impl Default for DefaultClassChampionshipResultsParser {
     fn default() -> Self {
-        Self {
-            points_calculator: Box::new(DefaultChampionshipPointsCalculator {}),
-        }
+        Self::from(None)
     }
 }
 
 impl DefaultClassChampionshipResultsParser {
+    pub fn from(
+        points_calculator: Option<Box<dyn ChampionshipPointsCalculator>>,
+    ) -> DefaultClassChampionshipResultsParser {
+        Self {
+            points_calculator: points_calculator.unwrap_or_else(|| Box::new(DefaultChampionshipPointsCalculator {})),
+        }
+    }
+
     fn parse_sheet(
         &self,
         header_map: HashMap<String, usize>,
@@ -94,7 +100,7 @@ impl DefaultClassChampionshipResultsParser {
             .get("Driver")
             .ok_or_else(|| "Missing 'Driver' column".to_string())?;
         let total_points_index = *header_map
-            .get("Total\nPoints")
+            .get("Total Points")
             .ok_or_else(|| "Missing 'Total Points' column".to_string())?;
         for r in data.rows() {
             if !r.is_empty() {
@@ -132,10 +138,7 @@ impl DefaultClassChampionshipResultsParser {
         r: &[Data],
     ) {
         let rows_for_one_class = rows_by_class_and_driver_id.get_mut(current_class).unwrap_or_else(|| {
-            panic!(
-                "Attempted to retrieve driver map for class {:?} but map was not found",
-                current_class
-            )
+            panic!("Attempted to retrieve driver map for class {current_class:?} but map was not found")
         });
         let name = r[name_index].to_string();
 
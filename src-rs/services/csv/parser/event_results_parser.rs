@@ -1,18 +1,31 @@
+use crate::enums::class_source::ClassSource;
+use crate::enums::short_car_class::ShortCarClass;
 use crate::models::class_results::ClassResults;
 use crate::models::driver::Driver;
 use crate::models::driver_from_pronto::DriverFromPronto;
+use crate::models::event::Event;
 use crate::models::event_results::EventResults;
 use crate::models::lap_time::{LapTime, Penalty};
 use crate::models::msr_driver::MsrDriver;
-use crate::models::type_aliases::{PaxMultiplier, Time};
+use crate::models::source_discrepancy::SourceDiscrepancy;
+use crate::models::type_aliases::{DriverId, PaxMultiplier, Time};
 use bigdecimal::ParseBigDecimalError;
 use csv::{StringRecord, Trim};
 use std::collections::HashMap;
 use std::num::ParseIntError;
 use std::str::FromStr;
 
-pub fn parse(msr_export: String, pronto_export: String) -> Result<EventResults, String> {
-    let msr_data = parse_msr(msr_export)?;
+/// Parse a Pronto export into event results. When an MSR export is provided, it is used for each driver's
+/// classification flags, `class_source` decides which export's class wins (along with its PAX index), and every field
+/// that differs between the two exports is recorded; otherwise, drivers are built purely from the Pronto data. `event`
+/// describes the event and is carried through to every output.
+pub fn parse(
+    msr_export: Option<String>,
+    pronto_export: String,
+    class_source: ClassSource,
+    event: Event,
+) -> Result<EventResults, String> {
+    let msr_data = msr_export.map(parse_msr).transpose()?;
 
     let mut pronto_reader = csv::ReaderBuilder::new()
         .flexible(true)
@@ -23,8 +36,8 @@ pub fn parse(msr_export: String, pronto_export: String) -> Result<EventResults,
         .flexible(true)
         .trim(Trim::Headers)
         .from_reader(pronto_export.as_bytes());
+    let headers = pronto_string_reader.headers().map_err(|e| e.to_string())?.clone();
     let pronto_final_column_index = {
-        let headers = pronto_string_reader.headers().map_err(|e| e.to_string())?;
         let header_vec: Vec<&str> = headers.iter().collect();
         header_vec
             .iter()
@@ -34,18 +47,38 @@ pub fn parse(msr_export: String, pronto_export: String) -> Result<EventResults,
             .ok_or("Unable to find header column `Runs Day2`".to_string())?
     };
 
-    let mut results = HashMap::new();
+    let mut drivers = vec![];
+    let mut moved = vec![];
+    let mut discrepancies = vec![];
+    let mut no_shows = vec![];
 
     let pronto_records = pronto_reader.deserialize().zip(pronto_string_reader.records());
     for (deserialized, string_rec) in pronto_records {
-        let (driver, string_rec) = validate_row(deserialized, string_rec)?;
+        let (driver, string_rec) = match validate_row(deserialized, string_rec, &headers)? {
+            ProntoRow::Driver(driver, string_rec) => (driver, string_rec),
+            ProntoRow::NoShow(driver) => {
+                no_shows.push(build_driver(
+                    driver,
+                    msr_data.as_ref(),
+                    class_source,
+                    &mut discrepancies,
+                ));
+                continue;
+            }
+        };
 
         let driver = extract_lap_times(driver, string_rec, pronto_final_column_index + 1)?;
-        let msr_driver = msr_data
-            .get(&driver.id())
-            .ok_or(format!("Failed to match Pronto driver {} in MSR data", driver.id()))?;
-        let driver = Driver::from((driver, msr_driver));
+        let pronto_class = driver.car_class;
+        let driver = build_driver(driver, msr_data.as_ref(), class_source, &mut discrepancies);
+        if driver.car_class.short != pronto_class && driver.index_class() != pronto_class {
+            moved.push(driver.id.clone());
+        }
+        drivers.push(driver);
+    }
+    reindex_moved_drivers(&mut drivers, &moved, &mut discrepancies)?;
 
+    let mut results = HashMap::new();
+    for driver in drivers {
         results
             .entry(driver.car_class.short)
             .or_insert_with(|| ClassResults::new(driver.car_class.short));
@@ -55,10 +88,103 @@ pub fn parse(msr_export: String, pronto_export: String) -> Result<EventResults,
         }
     }
 
-    Ok(EventResults { results })
+    link_co_drivers(&mut results);
+    Ok(EventResults {
+        event,
+        results,
+        discrepancies,
+        no_shows,
+    })
 }
 
-fn parse_msr(msr_export: String) -> Result<HashMap<String, MsrDriver>, String> {
+/// Pronto indexes a driver, and each of their runs, for the class Pronto has them in. Drivers moved to their MSR class
+/// are re-indexed with the index the rest of that class ran on at this event, and the change is recorded.
+fn reindex_moved_drivers(
+    drivers: &mut [Driver],
+    moved: &[DriverId],
+    discrepancies: &mut Vec<SourceDiscrepancy>,
+) -> Result<(), String> {
+    let mut counts: HashMap<ShortCarClass, HashMap<PaxMultiplier, usize>> = HashMap::new();
+    drivers
+        .iter()
+        .filter(|d| !moved.contains(&d.id) && d.index_class() != ShortCarClass::P)
+        .for_each(|d| {
+            *counts
+                .entry(d.index_class())
+                .or_default()
+                .entry(d.pax_multiplier.clone())
+                .or_default() += 1
+        });
+
+    for driver in drivers.iter_mut().filter(|d| moved.contains(&d.id)) {
+        let class = driver.index_class();
+        let pax = counts
+            .get(&class)
+            .and_then(|counts| {
+                counts
+                    .iter()
+                    .max_by(|(lhs_pax, lhs_count), (rhs_pax, rhs_count)| {
+                        lhs_count.cmp(rhs_count).then(rhs_pax.cmp(lhs_pax))
+                    })
+                    .map(|(pax, _)| pax.clone())
+            })
+            .ok_or_else(|| {
+                format!(
+                    "Unable to find the {} PAX index for {}, whose class was taken from MSR; no one else ran in {}",
+                    class.name(),
+                    driver.name,
+                    class.name()
+                )
+            })?;
+        if pax != driver.pax_multiplier {
+            discrepancies.push(SourceDiscrepancy::reindexed(driver, &pax));
+            driver.times.iter_mut().for_each(|lap| lap.pax = pax.clone());
+            driver.pax_multiplier = pax;
+        }
+    }
+    Ok(())
+}
+
+/// Record on each driver the names of everyone else who drove the same car
+fn link_co_drivers(results: &mut HashMap<ShortCarClass, ClassResults>) {
+    let drivers = results
+        .values()
+        .flat_map(|class_results| class_results.drivers.iter().cloned())
+        .collect::<Vec<Driver>>();
+    results
+        .values_mut()
+        .flat_map(|class_results| class_results.drivers.iter_mut())
+        .for_each(|driver| {
+            driver.co_drivers = drivers
+                .iter()
+                .filter(|other| driver.is_co_driver_of(other))
+                .map(|other| other.name.clone())
+                .collect();
+        });
+}
+
+fn build_driver(
+    driver: DriverFromPronto,
+    msr_data: Option<&HashMap<String, MsrDriver>>,
+    class_source: ClassSource,
+    discrepancies: &mut Vec<SourceDiscrepancy>,
+) -> Driver {
+    match msr_data {
+        None => Driver::from(driver),
+        Some(msr_data) => match msr_data.get(&driver.id()) {
+            Some(msr_driver) => {
+                discrepancies.extend(SourceDiscrepancy::compare(&driver, msr_driver, class_source));
+                Driver::from((driver, msr_driver, class_source))
+            }
+            None => {
+                discrepancies.push(SourceDiscrepancy::missing_from_msr(&driver));
+                Driver::from(driver)
+            }
+        },
+    }
+}
+
+pub fn parse_msr(msr_export: String) -> Result<HashMap<String, MsrDriver>, String> {
     let mut reader = csv::ReaderBuilder::new()
         .flexible(true)
         .trim(Trim::Headers)
@@ -82,22 +208,29 @@ fn parse_msr(msr_export: String) -> Result<HashMap<String, MsrDriver>, String> {
     Ok(results)
 }
 
+enum ProntoRow {
+    Driver(DriverFromPronto, StringRecord),
+    /// A registration that never took a run, so Pronto cut the row short
+    NoShow(DriverFromPronto),
+}
+
 fn validate_row(
     deserialized: csv::Result<DriverFromPronto>,
     string_record: csv::Result<StringRecord>,
-) -> Result<(DriverFromPronto, StringRecord), String> {
+    headers: &StringRecord,
+) -> Result<ProntoRow, String> {
     let string_record = string_record.map_err(|e| e.to_string())?;
 
     match deserialized {
-        Ok(driver) => Ok((driver, string_record)),
+        Ok(driver) => Ok(ProntoRow::Driver(driver, string_record)),
         Err(e) => match e.kind() {
             csv::ErrorKind::Deserialize { err: root, .. } => {
                 match root.kind() {
-                    csv::DeserializeErrorKind::UnexpectedEndOfRow => Err(
-                        format!(
-                            "Encountered an unexpected end of row for a record. One common reason for this is a driver that did not attend the event but remains in Pronto.\n'{string_record:?}'",
-                        )
-                    ),
+                    csv::DeserializeErrorKind::UnexpectedEndOfRow => parse_no_show(&string_record, headers)
+                        .map(ProntoRow::NoShow)
+                        .map_err(|_| format!(
+                            "Encountered an unexpected end of row for a record. The row is malformed: it ends without a readable PAX index, so it can't be read as a result or as a no-show registration.\n'{string_record:?}'",
+                        )),
                     _ => Err(format!("Failed to deserialize row {string_record:?} due to {:?}", e.to_string())),
                 }
             }
@@ -106,6 +239,18 @@ fn validate_row(
     }
 }
 
+/// Pad a truncated row out to the full header width so the registration can still be read. The row must at least
+/// reach the PAX index column, or there isn't enough of a registration left to report.
+fn parse_no_show(string_record: &StringRecord, headers: &StringRecord) -> Result<DriverFromPronto, String> {
+    let mut padded = string_record.clone();
+    while padded.len() < headers.len() {
+        padded.push_field("");
+    }
+    let driver: DriverFromPronto = padded.deserialize(Some(headers)).map_err(|e| e.to_string())?;
+    PaxMultiplier::from_str(&driver.pax_multiplier).map_err(|e| e.to_string())?;
+    Ok(driver)
+}
+
 fn extract_lap_times(
     mut driver: DriverFromPronto,
     string_record: StringRecord,
@@ -159,13 +304,38 @@ fn build_lap_time(next_fields: &[&str], pax: &PaxMultiplier) -> Result<LapTime,
     ))
 }
 
+/// 2022 Event 1 registered in MSR by Robert Fullriede (AS #52), Jeffrey Osborn (AS #77) and Adam Buffa (BS #3), with
+/// the member number and region of record at the same index
+#[cfg(test)]
+pub(crate) fn sample_event_with_msr(member_numbers: [&str; 3], regions: [&str; 3], event: Event) -> EventResults {
+    let header = r#""Last Name","First Name","Member #","Class + Modifier/PAX","No.","Vehicle Year/Make/Model/Color","Region of Record Abbreviation","Medical condition? (Optional)","Is a pro","Novice","Ladies""#;
+    let msr_export = [
+        ("Fullriede", "Robert", "AS", 52, "Vette"),
+        ("Osborn", "Jeffrey", "AS", 77, "Foobar"),
+        ("Buffa", "Adam", "BS", 3, "Supra"),
+    ]
+    .iter()
+    .zip(member_numbers.iter().zip(regions.iter()))
+    .fold(
+        header.to_string(),
+        |csv, ((last, first, class, number, car), (member, region))| {
+            format!("{csv}\n{last},{first},{member},{class},{number},{car},{region},,0,0,0")
+        },
+    );
+    let pronto_export = std::fs::read_to_string("./SampleData/2022/2022_Event1-DavidExport.csv").unwrap();
+    parse(Some(msr_export), pronto_export, ClassSource::Pronto, event).unwrap()
+}
+
 #[cfg(test)]
 mod test {
+    use crate::enums::class_source::ClassSource;
     use crate::enums::short_car_class::ShortCarClass;
-    use crate::models::lap_time::{LapTime, Penalty};
+    use crate::models::event::Event;
+    use crate::models::lap_time::{dns, LapTime, Penalty};
     use crate::models::type_aliases::{PaxMultiplier, Time};
     use crate::services::csv::parser::event_results_parser::parse;
     use bigdecimal::Zero;
+    use rstest::rstest;
     use std::fs;
     use std::str::FromStr;
 
@@ -180,7 +350,13 @@ Greer,Sean,5,SS,5,Bug,,,0,0,0"###;
     #[test]
     fn parse_2022_e1_event_results() {
         let sample_contents = fs::read_to_string("./SampleData/2022/2022_Event1-DavidExport.csv").unwrap();
-        let actual = parse(MSR_DATA.to_string(), sample_contents).unwrap();
+        let actual = parse(
+            Some(MSR_DATA.to_string()),
+            sample_contents,
+            ClassSource::Pronto,
+            Event::default(),
+        )
+        .unwrap();
 
         assert_eq!(actual.results.len(), 2);
         assert!(actual.results.contains_key(&ShortCarClass::AS));
@@ -244,7 +420,13 @@ Greer,Sean,5,SS,5,Bug,,,0,0,0"###;
     fn parse_2023_e3_event_results() {
         let sample_contents = fs::read_to_string("./SampleData/2023/2023_Event3-DavidExport.csv").unwrap();
 
-        let actual = parse(MSR_DATA.to_string(), sample_contents).unwrap();
+        let actual = parse(
+            Some(MSR_DATA.to_string()),
+            sample_contents,
+            ClassSource::Pronto,
+            Event::default(),
+        )
+        .unwrap();
         assert!(actual.results.contains_key(&ShortCarClass::AS));
         assert_eq!(actual.results.len(), 1);
 
@@ -321,6 +503,168 @@ Greer,Sean,5,SS,5,Bug,,,0,0,0"###;
         }
     }
 
+    #[test]
+    fn parse_without_msr_uses_pronto_flags() {
+        let sample_contents = fs::read_to_string("./SampleData/2022/2022_Event1-DavidExport.csv").unwrap();
+        let actual = parse(None, sample_contents, ClassSource::Pronto, Event::default()).unwrap();
+
+        assert_eq!(actual.results.len(), 2);
+        assert!(actual.discrepancies.is_empty());
+
+        let robert = actual.results.get(&ShortCarClass::AS).unwrap().drivers[0].clone();
+        assert_eq!(robert.id, "robert fullriede");
+        assert_eq!(robert.member_number, "135655");
+        assert_eq!(robert.car_class.short, ShortCarClass::AS);
+        assert_eq!(robert.pax_class, None);
+        assert_eq!(robert.region, "");
+        assert!(!robert.rookie);
+        assert!(!robert.ladies_championship);
+    }
+
+    #[test]
+    fn parse_with_msr_reports_discrepancies() {
+        let sample_contents = fs::read_to_string("./SampleData/2022/2022_Event1-DavidExport.csv").unwrap();
+        let actual = parse(
+            Some(MSR_DATA.to_string()),
+            sample_contents,
+            ClassSource::Pronto,
+            Event::default(),
+        )
+        .unwrap();
+
+        let robert_discrepancies = actual
+            .discrepancies
+            .iter()
+            .filter(|d| d.driver_id == "robert fullriede")
+            .map(|d| (d.field.as_str(), d.pronto.as_str(), d.msr.as_str()))
+            .collect::<Vec<(&str, &str, &str)>>();
+        assert_eq!(
+            robert_discrepancies,
+            vec![
+                ("Member #", "135655", "1"),
+                ("Number", "52", "1"),
+                ("Region", "", "STL")
+            ]
+        );
+        assert_eq!(actual.results.get(&ShortCarClass::AS).unwrap().drivers[0].region, "STL");
+    }
+
+    #[test]
+    fn parse_with_driver_missing_from_msr() {
+        // language=csv
+        let sample_contents = r#"Position, Class, Class Category, Class Name, Number, First Name,Last Name, Car Year, Car Make, Car Model, Car Color, Member #, Rookie, Ladies, DSQ, Region, Best Run, Pax Index, Pax Time, Runs Day1, Runs Day2, Runs (Time/Cones/Penalty)
+"1","CS","Street","C Street","7","Walk","Up","2019","Mazda","Miata","Red","","1","0","0","STL","50.000","0.8","40.000","1","0","50.000","0","""#;
+
+        let actual = parse(
+            Some(MSR_DATA.to_string()),
+            sample_contents.to_string(),
+            ClassSource::Pronto,
+            Event::default(),
+        )
+        .unwrap();
+
+        let walk_up = actual.results.get(&ShortCarClass::CS).unwrap().drivers[0].clone();
+        assert!(walk_up.rookie);
+        assert_eq!(walk_up.region, "STL");
+        assert_eq!(actual.discrepancies.len(), 1);
+        assert_eq!(actual.discrepancies[0].field, "Registration");
+        assert_eq!(actual.discrepancies[0].driver_name, "Walk Up");
+    }
+
+    #[rstest]
+    #[case(ClassSource::Pronto, ShortCarClass::BS, "0.8", 1)]
+    #[case(ClassSource::MSR, ShortCarClass::AS, "0.821", 2)]
+    fn parse_reconciles_class_mismatch(
+        #[case] class_source: ClassSource,
+        #[case] expected: ShortCarClass,
+        #[case] expected_pax: &str,
+        #[case] expected_discrepancies: usize,
+    ) {
+        // language=csv
+        let sample_contents = r#"Position, Class, Class Category, Class Name, Number, First Name,Last Name, Car Year, Car Make, Car Model, Car Color, Member #, Rookie, Ladies, DSQ, Region, Best Run, Pax Index, Pax Time, Runs Day1, Runs Day2, Runs (Time/Cones/Penalty)
+"1","BS","Street","B Street","4","Adrian","Sweetwood","2003","Chevrolet","Corvette Z06","Yellow","4","0","0","0","STL","50.000","0.8","40.000","1","0","50.000","0",""
+"1","AS","Street","A Street","1","Robert","Fullriede","2010","Porsche","Cayman","Red","1","0","0","0","STL","52.000","0.821","42.692","1","0","52.000","0","""#;
+
+        let actual = parse(
+            Some(MSR_DATA.to_string()),
+            sample_contents.to_string(),
+            class_source,
+            Event::default(),
+        )
+        .unwrap();
+
+        let adrian = actual
+            .get_drivers(None)
+            .into_iter()
+            .find(|d| d.name == "Adrian Sweetwood")
+            .unwrap()
+            .clone();
+        let expected_pax = PaxMultiplier::from_str(expected_pax).unwrap();
+        assert_eq!(adrian.car_class.short, expected);
+        assert_eq!(adrian.pax_multiplier, expected_pax);
+        assert!(adrian.times.iter().all(|lap| lap.pax == expected_pax));
+        assert_eq!(
+            adrian.best_standard_lap().with_pax(),
+            Some(Time::from(50) * expected_pax.clone())
+        );
+        assert_eq!(actual.discrepancies.len(), expected_discrepancies);
+        assert_eq!(actual.discrepancies[0].field, "Class");
+        assert_eq!(actual.discrepancies[0].pronto, "BS");
+        assert_eq!(actual.discrepancies[0].msr, "AS");
+        assert_eq!(actual.discrepancies[0].used, expected.name());
+        if class_source == ClassSource::MSR {
+            assert_eq!(actual.discrepancies[1].field, "PAX");
+            assert_eq!(actual.discrepancies[1].pronto, "0.8");
+            assert_eq!(actual.discrepancies[1].used, "0.821");
+        }
+    }
+
+    #[test]
+    fn parse_rejects_msr_class_without_a_pax_index() {
+        // language=csv
+        let sample_contents = r#"Position, Class, Class Category, Class Name, Number, First Name,Last Name, Car Year, Car Make, Car Model, Car Color, Member #, Rookie, Ladies, DSQ, Region, Best Run, Pax Index, Pax Time, Runs Day1, Runs Day2, Runs (Time/Cones/Penalty)
+"1","BS","Street","B Street","4","Adrian","Sweetwood","2003","Chevrolet","Corvette Z06","Yellow","4","0","0","0","STL","50.000","0.8","40.000","1","0","50.000","0","""#;
+
+        let actual = parse(
+            Some(MSR_DATA.to_string()),
+            sample_contents.to_string(),
+            ClassSource::MSR,
+            Event::default(),
+        );
+
+        assert_eq!(
+            actual.err(),
+            Some(
+                "Unable to find the AS PAX index for Adrian Sweetwood, whose class was taken from MSR; no one else ran in AS"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn parse_never_defaults_unparseable_msr_class() {
+        // language=csv
+        let msr_data = r###""Last Name","First Name","Member #","Class + Modifier/PAX","No.","Vehicle Year/Make/Model/Color","Region of Record Abbreviation","Medical condition? (Optional)","Is a pro","Novice","Ladies"
+Sweetwood,Adrian,4,Bogus,4,Vette,STL,,0,0,0"###;
+        // language=csv
+        let sample_contents = r#"Position, Class, Class Category, Class Name, Number, First Name,Last Name, Car Year, Car Make, Car Model, Car Color, Member #, Rookie, Ladies, DSQ, Region, Best Run, Pax Index, Pax Time, Runs Day1, Runs Day2, Runs (Time/Cones/Penalty)
+"1","BS","Street","B Street","4","Adrian","Sweetwood","2003","Chevrolet","Corvette Z06","Yellow","4","0","0","0","STL","50.000","0.8","40.000","1","0","50.000","0","""#;
+
+        let actual = parse(
+            Some(msr_data.to_string()),
+            sample_contents.to_string(),
+            ClassSource::MSR,
+            Event::default(),
+        )
+        .unwrap();
+
+        assert!(actual.results.contains_key(&ShortCarClass::BS));
+        assert!(!actual.results.contains_key(&ShortCarClass::AM));
+        assert_eq!(actual.discrepancies[0].field, "Class");
+        assert_eq!(actual.discrepancies[0].msr, "Bogus");
+        assert_eq!(actual.discrepancies[0].used, "BS");
+    }
+
     #[test]
     fn parse_results_with_no_show_driver() {
         // language=csv
@@ -328,9 +672,84 @@ Greer,Sean,5,SS,5,Bug,,,0,0,0"###;
 "1","SS","Street","Super Street","78","Sean","Greer","2022","Chevrolet","Challenger Cobra 392","urine","432501","0","","0","STL","41.442","0.83","34.397","6","0","42.429","0","","41.862","0","","41.595","0","","41.537","0","","41.445","0","","41.442","0",""
 "17","CAMT","Other","Classic American Muscle Traditional","88","Charles","Hammelman","1999","Ford","Mustang SVT Cobra","Black","691686","1","","0","","DNF","0.816","999","0","0""#;
 
-        let actual = parse(MSR_DATA.to_string(), sample_contents.to_string());
+        let actual = parse(
+            Some(MSR_DATA.to_string()),
+            sample_contents.to_string(),
+            ClassSource::Pronto,
+            Event::default(),
+        )
+        .unwrap();
 
-        assert!(actual.is_err(), "Should fail on empty driver");
-        assert_eq!(actual.err().unwrap(), "Encountered an unexpected end of row for a record. One common reason for this is a driver that did not attend the event but remains in Pronto.\n'StringRecord([\"17\", \"CAMT\", \"Other\", \"Classic American Muscle Traditional\", \"88\", \"Charles\", \"Hammelman\", \"1999\", \"Ford\", \"Mustang SVT Cobra\", \"Black\", \"691686\", \"1\", \"\", \"0\", \"\", \"DNF\", \"0.816\", \"999\", \"0\", \"0\"])'");
+        assert_eq!(actual.results.len(), 1);
+        assert!(actual.results.contains_key(&ShortCarClass::SS));
+        assert!(!actual.results.contains_key(&ShortCarClass::CAMT));
+
+        assert_eq!(actual.no_shows.len(), 1);
+        let charles = actual.no_shows[0].clone();
+        assert_eq!(charles.name, "Charles Hammelman");
+        assert_eq!(charles.car_number, 88);
+        assert_eq!(charles.car_class.short, ShortCarClass::CAMT);
+        assert!(charles.times.is_empty());
+        assert_eq!(charles.best_standard_lap(), dns());
+    }
+
+    #[test]
+    fn parse_results_with_unreadable_short_row() {
+        // language=csv
+        let sample_contents = r#"Position, Class, Class Category, Class Name, Number, First Name,Last Name, Car Year, Car Make, Car Model, Car Color, Member #, Rookie, Ladies, DSQ, Region, Best Run, Pax Index, Pax Time, Runs Day1, Runs Day2, Runs (Time/Cones/Penalty)
+"17","CAMT","Other","Classic American Muscle Traditional","88""#;
+
+        let actual = parse(
+            Some(MSR_DATA.to_string()),
+            sample_contents.to_string(),
+            ClassSource::Pronto,
+            Event::default(),
+        );
+
+        assert!(actual.is_err(), "Should fail on a row without a registration");
+        assert!(actual
+            .err()
+            .unwrap()
+            .starts_with("Encountered an unexpected end of row for a record. The row is malformed"));
+    }
+
+    #[test]
+    fn parse_links_co_drivers() {
+        // language=csv
+        let sample_contents = r#"Position, Class, Class Category, Class Name, Number, First Name,Last Name, Car Year, Car Make, Car Model, Car Color, Member #, Rookie, Ladies, DSQ, Region, Best Run, Pax Index, Pax Time, Runs Day1, Runs Day2, Runs (Time/Cones/Penalty)
+"1","SS","Street","Super Street","78","Sean","Greer","2022","Dodge","Challenger","Red","1","0","0","0","STL","41.442","0.83","34.397","1","0","41.442","0",""
+"2","SS","Street","Super Street","178","Pat","Greer","2022","Dodge","Challenger","Red","2","0","0","0","STL","42.429","0.83","35.216","1","0","42.429","0",""
+"3","SS","Street","Super Street","98","John","McDonnell","2023","Chevrolet","Corvette","Gray","3","0","0","0","STL","43.016","0.83","35.703","1","0","43.016","0",""
+"1","AS","Street","A Street","12","Steven","Burkett","2023","Chevrolet","Corvette","Gray","4","0","0","0","STL","44.811","0.82","36.745","1","0","44.811","0",""
+"2","AS","Street","A Street","198","Jo","Other","2023","Chevrolet","Camaro","Gray","5","0","0","0","STL","45.811","0.82","37.565","1","0","45.811","0",""#;
+        // language=csv
+        let msr_data = r###""Last Name","First Name","Member #","Class + Modifier/PAX","No.","Vehicle Year/Make/Model/Color","Region of Record Abbreviation","Medical condition? (Optional)","Is a pro","Novice","Ladies","Co Driver Number"
+McDonnell,John,3,SS,98,Corvette,STL,,0,0,0,12
+Burkett,Steven,4,AS,12,Corvette,STL,,0,0,0,"###;
+
+        let actual = parse(
+            Some(msr_data.to_string()),
+            sample_contents.to_string(),
+            ClassSource::Pronto,
+            Event::default(),
+        )
+        .unwrap();
+
+        let co_drivers = actual
+            .get_drivers(None)
+            .iter()
+            .map(|d| (d.name.as_str(), d.co_drivers.clone()))
+            .collect::<Vec<(&str, Vec<String>)>>();
+        assert_eq!(
+            co_drivers,
+            vec![
+                ("Sean Greer", vec!["Pat Greer".to_string()]),
+                ("Pat Greer", vec!["Sean Greer".to_string()]),
+                ("John McDonnell", vec!["Steven Burkett".to_string()]),
+                ("Steven Burkett", vec!["John McDonnell".to_string()]),
+                // Numbered as #98's co-driver, but in a different car
+                ("Jo Other", vec![]),
+            ]
+        );
     }
 }
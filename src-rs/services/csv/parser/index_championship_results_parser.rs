@@ -84,13 +84,19 @@ impl IndexChampionshipResultsParser for DefaultIndexChampionshipResultsParser {
 
 impl Default for DefaultIndexChampionshipResultsParser {
     fn default() -> Self {
-        Self {
-            points_calculator: Box::new(DefaultChampionshipPointsCalculator {}),
-        }
+        Self::from(None)
     }
 }
 
 impl DefaultIndexChampionshipResultsParser {
+    pub fn from(
+        points_calculator: Option<Box<dyn ChampionshipPointsCalculator>>,
+    ) -> DefaultIndexChampionshipResultsParser {
+        Self {
+            points_calculator: points_calculator.unwrap_or_else(|| Box::new(DefaultChampionshipPointsCalculator {})),
+        }
+    }
+
     fn parse_sheet(
         &self,
         header_map: HashMap<String, usize>,
@@ -100,7 +106,7 @@ impl DefaultIndexChampionshipResultsParser {
             .get("Driver")
             .ok_or_else(|| "Missing 'Driver' column".to_string())?;
         let total_points_index = *header_map
-            .get("Total\nPoints")
+            .get("Total Points")
             .ok_or_else(|| "Missing 'Total Points' column".to_string())?;
 
         Ok(data
@@ -0,0 +1,127 @@
+use crate::enums::driver_group::DriverGroup;
+use crate::enums::long_car_class::to_display_name;
+use crate::models::event_results::EventResults;
+use crate::services::csv::builder::event::class_results_builder::ClassResultsBuilder;
+use crate::services::csv::builder::event::combined_results_builder::CombinedResultsBuilder;
+use crate::services::html::template::{escape, event_details, page, table};
+
+/// Render official results for a single event as a self-contained HTML page: a table per class followed by the
+/// overall PAX, Raw, Novice and Ladies tables. Trophy positions are highlighted, and co-drivers are noted next to each
+/// other's names in the class tables.
+#[derive(Default)]
+pub struct EventResultsPage {
+    class_results_builder: ClassResultsBuilder,
+    combined_results_builder: CombinedResultsBuilder,
+}
+
+impl EventResultsPage {
+    pub fn render(&self, results: &EventResults) -> Result<String, String> {
+        let class_results = results.sorted_class_results();
+
+        let mut body = event_details(&results.event);
+        body.push_str("<nav>");
+        class_results.iter().for_each(|class_results| {
+            let name = class_results.car_class.short.name();
+            body.push_str(&format!("<a href=\"#{name}\">{name}</a>"));
+        });
+        [
+            DriverGroup::PAX,
+            DriverGroup::Raw,
+            DriverGroup::Novice,
+            DriverGroup::Ladies,
+        ]
+        .iter()
+        .for_each(|group| body.push_str(&format!("<a href=\"#{}\">{}</a>", group.name(), group.name())));
+        body.push_str("</nav>\n");
+
+        let header = Self::escape_cells(self.class_results_builder.get_header_cells());
+        class_results.iter().for_each(|class_results| {
+            let rows = self
+                .class_results_builder
+                .build_rows(class_results)
+                .iter()
+                .map(|row| {
+                    let mut cells = row.to_record();
+                    if !row.co_drivers.is_empty() {
+                        cells[2].push_str(&format!(" (co-driver: {})", row.co_drivers.join(", ")));
+                    }
+                    Self::to_row(row.trophy, cells)
+                })
+                .collect::<Vec<(Option<&str>, Vec<String>)>>();
+            body.push_str(&format!(
+                "<h2 id=\"{}\">{} - {}</h2>\n{}",
+                class_results.car_class.short.name(),
+                class_results.car_class.short.name(),
+                escape(&to_display_name(class_results.car_class.long)),
+                table(&header, &rows)
+            ));
+        });
+
+        for group in [
+            DriverGroup::PAX,
+            DriverGroup::Raw,
+            DriverGroup::Novice,
+            DriverGroup::Ladies,
+        ] {
+            body.push_str(&format!("<h2 id=\"{}\">{}</h2>\n", group.name(), group.name()));
+            let rows = self
+                .combined_results_builder
+                .to_combined_rows(results, group)?
+                .iter()
+                .map(|row| Self::to_row(row.trophy, row.to_record()))
+                .collect::<Vec<(Option<&str>, Vec<String>)>>();
+            if rows.is_empty() {
+                body.push_str(&format!("<p>No drivers for {} group</p>\n", group.name()));
+            } else {
+                let header = self
+                    .combined_results_builder
+                    .get_combined_header(group == DriverGroup::Raw);
+                body.push_str(&table(&Self::escape_cells(header), &rows));
+            }
+        }
+
+        Ok(page(&results.event.title(), &body))
+    }
+
+    fn escape_cells(cells: Vec<String>) -> Vec<String> {
+        cells.iter().map(|cell| escape(cell)).collect()
+    }
+
+    fn to_row<'a>(trophy: bool, cells: Vec<String>) -> (Option<&'a str>, Vec<String>) {
+        (if trophy { Some("trophy") } else { None }, Self::escape_cells(cells))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use crate::enums::class_source::ClassSource;
+    use crate::enums::short_car_class::ShortCarClass;
+    use crate::models::event::Event;
+    use crate::services::csv::parser::event_results_parser::parse;
+    use crate::services::html::event_results_page::EventResultsPage;
+
+    #[test]
+    fn renders_class_and_overall_tables() {
+        let sample_contents = fs::read_to_string("./SampleData/2022/2022_Event1-DavidExport.csv").unwrap();
+        let event = Event {
+            date: Some("2022-04-10".to_string()),
+            ..Event::new("Event 1".to_string())
+        };
+        let mut results = parse(None, sample_contents, ClassSource::Pronto, event).unwrap();
+        results.results.get_mut(&ShortCarClass::AS).unwrap().drivers[1].co_drivers = vec!["Adam Buffa".to_string()];
+
+        let actual = EventResultsPage::default().render(&results).unwrap();
+
+        assert!(actual.starts_with("<!DOCTYPE html>"));
+        assert!(actual.contains("<h1>Event 1</h1>\n<p class=\"event\">2022-04-10</p>\n<nav>"));
+        assert!(actual.contains("<nav><a href=\"#AS\">AS</a><a href=\"#BS\">BS</a><a href=\"#PAX\">PAX</a>"));
+        assert!(actual.contains("<h2 id=\"AS\">AS - A Street</h2>"));
+        assert!(actual.contains("<tr class=\"trophy\"><td>T</td><td>1</td><td>Robert Fullriede</td>"));
+        assert!(actual.contains("<td>Jeffrey Osborn (co-driver: Adam Buffa)</td>"));
+        assert!(actual.contains("<h2 id=\"PAX\">PAX</h2>\n<table>"));
+        assert!(actual.contains("<h2 id=\"Raw\">Raw</h2>\n<table>"));
+        assert!(!actual.contains("<script"));
+    }
+}
@@ -0,0 +1,96 @@
+use crate::enums::long_car_class::to_display_name;
+use crate::enums::short_car_class::ShortCarClass;
+use crate::models::class_results::ClassResults;
+use crate::models::event_results::EventResults;
+use crate::services::html::template::{escape, event_details, page, table};
+
+/// Render run-by-run results for a single event as a self-contained HTML page, one table per class with the counted
+/// best run of each driver highlighted
+#[derive(Default)]
+pub struct FullResultsPage {}
+
+impl FullResultsPage {
+    pub fn render(&self, results: &EventResults) -> String {
+        let classes = results
+            .sorted_class_results()
+            .iter()
+            .map(|class_results| self.render_class(class_results))
+            .collect::<Vec<String>>()
+            .join("");
+        page(
+            &results.event.title(),
+            &format!("{}{classes}", event_details(&results.event)),
+        )
+    }
+
+    fn render_class(&self, class_results: &ClassResults) -> String {
+        let pro = class_results.car_class.short == ShortCarClass::P;
+        let run_count = class_results.drivers.iter().map(|d| d.times.len()).max().unwrap_or(0);
+
+        let mut header = vec![
+            "Pos".to_string(),
+            "Name".to_string(),
+            "Number".to_string(),
+            "Car".to_string(),
+        ];
+        header.extend((0..run_count).map(|i| format!("Run {}", i + 1)));
+        header.push("Best".to_string());
+
+        let rows = class_results
+            .drivers
+            .iter()
+            .map(|driver| {
+                let best_lap_index = driver.best_lap_index(pro);
+                let mut cells = vec![
+                    driver.position.map(|p| format!("{p}")).unwrap_or_default(),
+                    escape(&driver.name),
+                    format!("{}", driver.car_number),
+                    escape(&driver.car_description),
+                ];
+                cells.extend((0..run_count).map(|index| match driver.times.get(index) {
+                    Some(lap) if best_lap_index == Some(index) => {
+                        format!("<span class=\"best\">{}</span>", lap.to_string(false, true))
+                    }
+                    Some(lap) => lap.to_string(false, true),
+                    None => "".to_string(),
+                }));
+                cells.push(driver.best_lap(pro).to_string(false, false));
+                (None, cells)
+            })
+            .collect::<Vec<(Option<&str>, Vec<String>)>>();
+
+        format!(
+            "<h2 id=\"{}\">{} - {}</h2>\n{}",
+            class_results.car_class.short.name(),
+            class_results.car_class.short.name(),
+            escape(&to_display_name(class_results.car_class.long)),
+            table(&header, &rows)
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use crate::enums::class_source::ClassSource;
+    use crate::models::event::Event;
+    use crate::services::csv::parser::event_results_parser::parse;
+    use crate::services::html::full_results_page::FullResultsPage;
+
+    #[test]
+    fn renders_every_run_by_class() {
+        let sample_contents = fs::read_to_string("./SampleData/2022/2022_Event1-DavidExport.csv").unwrap();
+        let results = parse(None, sample_contents, ClassSource::Pronto, Event::default()).unwrap();
+
+        let actual = FullResultsPage::default().render(&results);
+
+        assert!(actual.starts_with("<!DOCTYPE html>"));
+        assert!(actual.contains("<h2 id=\"AS\">AS - A Street</h2>"));
+        assert!(actual.contains("<h2 id=\"BS\">BS - B Street</h2>"));
+        assert!(actual.contains(
+            "<tr><td>1</td><td>Robert Fullriede</td><td>52</td><td>2010 Porsche Cayman</td>\
+<td>53.351</td><td>DNF</td><td><span class=\"best\">52.288</span></td><td></td><td></td><td>52.288</td></tr>"
+        ));
+    }
+}
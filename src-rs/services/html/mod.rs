@@ -0,0 +1,5 @@
+pub(crate) mod event_results_page;
+pub(crate) mod full_results_page;
+pub(crate) mod report_card_page;
+pub(crate) mod standings_page;
+pub(crate) mod template;
@@ -0,0 +1,168 @@
+use crate::models::report_card::ReportCard;
+use crate::services::html::template::{escape, page, table};
+
+/// Render a driver's season report card as a self-contained HTML page. Dropped results are struck through.
+#[derive(Default)]
+pub struct ReportCardPage {}
+
+impl ReportCardPage {
+    pub fn render(&self, report_card: &ReportCard) -> String {
+        let event_header = [
+            "Event",
+            "Class",
+            "Class Position",
+            "PAX Position",
+            "Gap to Class Leader",
+            "Normalized Score",
+        ]
+        .iter()
+        .map(|cell| cell.to_string())
+        .collect::<Vec<String>>();
+        let event_rows = report_card
+            .events
+            .iter()
+            .map(|event| {
+                let place = |position: Option<usize>, entries: usize| {
+                    position.map(|p| format!("{p} of {entries}")).unwrap_or_default()
+                };
+                (
+                    None,
+                    vec![
+                        escape(&event.event),
+                        if event.attended {
+                            event.car_class.map(|c| c.name()).unwrap_or_default().to_string()
+                        } else {
+                            "Did not attend".to_string()
+                        },
+                        place(event.class_position, event.class_entries),
+                        place(event.pax_position, event.pax_entries),
+                        event
+                            .gap_to_class_leader
+                            .as_ref()
+                            .map(|gap| escape(&gap.display))
+                            .unwrap_or_default(),
+                        event
+                            .normalized_score
+                            .map(|score| format!("{score:.2}"))
+                            .unwrap_or_default(),
+                    ],
+                )
+            })
+            .collect::<Vec<(Option<&str>, Vec<String>)>>();
+
+        let mut championship_header = vec!["Championship".to_string()];
+        championship_header.extend(report_card.events.iter().map(|event| escape(&event.event)));
+        championship_header.push("Counted Total".to_string());
+        championship_header.push("Best Event".to_string());
+        let championship_rows = report_card
+            .championships
+            .iter()
+            .map(|championship| {
+                let mut cells = vec![match championship.car_class {
+                    Some(car_class) => format!("{} ({})", championship.championship_type.name(), car_class.name()),
+                    None => championship.championship_type.name().to_string(),
+                }];
+                cells.extend(championship.points.iter().enumerate().map(|(index, points)| {
+                    let points = points.map(|p| format!("{p}")).unwrap_or_else(|| "-".to_string());
+                    if championship.dropped_events.contains(&(index + 1)) {
+                        format!("<s>{points}</s>")
+                    } else {
+                        points
+                    }
+                }));
+                cells.push(format!(
+                    "{} (best {})",
+                    championship.counted_total, championship.events_to_count
+                ));
+                cells.push(
+                    championship
+                        .best_event
+                        .and_then(|event| report_card.events.get(event - 1))
+                        .map(|event| escape(&event.event))
+                        .unwrap_or_default(),
+                );
+                (None, cells)
+            })
+            .collect::<Vec<(Option<&str>, Vec<String>)>>();
+
+        page(
+            &format!("{} - Season Report", report_card.driver),
+            &format!(
+                "<h2>Events</h2>\n{}<h2>Championships</h2>\n{}",
+                table(&event_header, &event_rows),
+                table(&championship_header, &championship_rows)
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::enums::championship_type::ChampionshipType;
+    use crate::enums::short_car_class::ShortCarClass;
+    use crate::models::report_card::{ReportCard, ReportCardChampionship, ReportCardEvent};
+    use crate::models::result_time::ResultTime;
+    use crate::services::html::report_card_page::ReportCardPage;
+
+    #[test]
+    fn strikes_through_dropped_events() {
+        let attended = ReportCardEvent {
+            event: "Event #1".to_string(),
+            attended: true,
+            car_class: Some(ShortCarClass::SS),
+            class_position: Some(2),
+            class_entries: 5,
+            pax_position: Some(10),
+            pax_entries: 80,
+            gap_to_class_leader: Some(ResultTime::new("0.512".to_string())),
+            normalized_score: Some(101.5),
+        };
+        let missed = ReportCardEvent {
+            event: "Event #2".to_string(),
+            attended: false,
+            car_class: None,
+            class_position: None,
+            class_entries: 0,
+            pax_position: None,
+            pax_entries: 0,
+            gap_to_class_leader: None,
+            normalized_score: None,
+        };
+        let report_card = ReportCard {
+            driver: "Sean Greer".to_string(),
+            events: vec![attended, missed],
+            championships: vec![ReportCardChampionship {
+                championship_type: ChampionshipType::Class,
+                car_class: Some(ShortCarClass::SS),
+                points: vec![Some(9_876), None],
+                events_to_count: 1,
+                counted_total: 9_876,
+                best_event: Some(1),
+                worst_counted_event: Some(1),
+                dropped_events: vec![2],
+            }],
+        };
+
+        let actual = ReportCardPage::default().render(&report_card);
+
+        assert!(actual.contains("<h1>Sean Greer - Season Report</h1>"));
+        let event_header = actual
+            .split("<thead>")
+            .nth(1)
+            .unwrap()
+            .split("</thead>")
+            .next()
+            .unwrap();
+        assert_eq!(event_header.matches("<th>").count(), 6);
+        assert!(event_header.ends_with("<th>Normalized Score</th></tr>"));
+        assert!(actual.contains(
+            "<tr><td>Event #1</td><td>SS</td><td>2 of 5</td><td>10 of 80</td><td>0.512</td><td>101.50</td></tr>"
+        ));
+        assert!(
+            actual.contains("<tr><td>Event #2</td><td>Did not attend</td><td></td><td></td><td></td><td></td></tr>")
+        );
+        assert!(actual.contains(
+            "<tr><td>Class (SS)</td><td>9876</td><td><s>-</s></td><td>9876 (best 1)</td><td>Event #1</td></tr>"
+        ));
+    }
+}
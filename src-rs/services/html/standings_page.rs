@@ -0,0 +1,127 @@
+use crate::models::championship_standings::ChampionshipStandings;
+use crate::models::event::Event;
+use crate::services::html::template::{escape, page, table};
+
+/// Render championship standings as a self-contained HTML page. Each class gets its own anchored table and trophy
+/// positions are highlighted.
+#[derive(Default)]
+pub struct StandingsPage {}
+
+impl StandingsPage {
+    /// `event` is the latest event included in the standings
+    pub fn render(&self, event: &Event, standings: &ChampionshipStandings) -> String {
+        let header = standings
+            .header()
+            .iter()
+            .map(|cell| escape(cell))
+            .collect::<Vec<String>>();
+
+        let mut body = format!(
+            "<p>{}</p>\n<p>Updated through {}</p>\n",
+            escape(&standings.organization),
+            escape(&event.title())
+        );
+        standings.sections.iter().for_each(|section| {
+            if let (Some(car_class), Some(label)) = (section.car_class, &section.label) {
+                body.push_str(&format!("<h2 id=\"{}\">{}</h2>\n", car_class.name(), escape(label)));
+            }
+            let rows = section
+                .rows
+                .iter()
+                .map(|row| {
+                    let mut cells = row.to_record();
+                    if row.visitor {
+                        cells[2].push_str(" (Visitor)");
+                    }
+                    (
+                        if row.trophy { Some("trophy") } else { None },
+                        cells.iter().map(|cell| escape(cell)).collect(),
+                    )
+                })
+                .collect::<Vec<(Option<&str>, Vec<String>)>>();
+            body.push_str(&table(&header, &rows));
+        });
+        page(&standings.title(), &body)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::enums::championship_type::ChampionshipType;
+    use crate::enums::short_car_class::ShortCarClass;
+    use crate::models::championship_standings::{ChampionshipRow, ChampionshipSection, ChampionshipStandings};
+    use crate::models::event::Event;
+    use crate::services::html::standings_page::StandingsPage;
+
+    fn row(trophy: bool, rank: usize, driver: &str, points: i64) -> ChampionshipRow {
+        ChampionshipRow {
+            trophy,
+            rank,
+            driver: driver.to_string(),
+            points: vec![points],
+            total_points: points,
+            best_of: points,
+            visitor: false,
+        }
+    }
+
+    #[test]
+    fn renders_class_standings() {
+        let mut standings = ChampionshipStandings {
+            championship_type: ChampionshipType::Class,
+            organization: "Org".to_string(),
+            year: 2022,
+            event_count: 1,
+            events_to_count: 1,
+            event_labels: vec!["Event #1".to_string()],
+            sections: vec![
+                ChampionshipSection {
+                    car_class: Some(ShortCarClass::AS),
+                    label: Some("AS - A Street".to_string()),
+                    rows: vec![
+                        row(true, 1, "Robert Fullriede", 10_000),
+                        row(false, 2, "Jeffrey Osborn", 9_800),
+                    ],
+                },
+                ChampionshipSection {
+                    car_class: Some(ShortCarClass::BS),
+                    label: Some("BS - B Street".to_string()),
+                    rows: vec![row(true, 1, "Adam Buffa", 10_000)],
+                },
+            ],
+        };
+        standings.flag_visitors(&["jeffrey osborn".to_string()]);
+
+        let actual = StandingsPage::default().render(&Event::new("Event 1".to_string()), &standings);
+
+        assert!(actual.contains(
+            "<h1>2022 Class Championship -- Best 1 of 1 Events</h1>\n<p>Org</p>\n<p>Updated through Event 1</p>\n<h2 id=\"AS\">AS - A Street</h2>\n<table>"
+        ));
+        assert!(actual.contains("<tr class=\"trophy\"><td>T</td><td>1</td><td>Robert Fullriede</td>"));
+        assert!(actual.contains("<tr><td></td><td>2</td><td>Jeffrey Osborn (Visitor)</td>"));
+        assert!(actual.contains("<h2 id=\"BS\">BS - B Street</h2>"));
+    }
+
+    #[test]
+    fn renders_indexed_standings() {
+        let standings = ChampionshipStandings {
+            championship_type: ChampionshipType::PAX,
+            organization: "Org".to_string(),
+            year: 2022,
+            event_count: 1,
+            events_to_count: 1,
+            event_labels: vec!["Event #1".to_string()],
+            sections: vec![ChampionshipSection {
+                car_class: None,
+                label: None,
+                rows: vec![row(true, 1, "Robert Fullriede", 10_000)],
+            }],
+        };
+
+        let actual = StandingsPage::default().render(&Event::new("Event 1".to_string()), &standings);
+
+        assert!(actual.contains("<h1>2022 PAX Championship -- Best 1 of 1 Events</h1>"));
+        assert!(!actual.contains("<h2 id="));
+        assert!(actual.contains("<tr class=\"trophy\"><td>T</td><td>1</td><td>Robert Fullriede</td>"));
+    }
+}
@@ -0,0 +1,84 @@
+use crate::models::event::Event;
+
+/// Minimal, self-contained styling so pages can be dropped onto the website without any other assets
+const STYLE: &str = "body{font-family:sans-serif;margin:1em}\
+table{border-collapse:collapse;margin-bottom:1.5em}\
+th,td{border:1px solid #999;padding:2px 6px;text-align:left}\
+th{background:#ddd}\
+tr.trophy td{font-weight:bold}\
+.best{font-weight:bold;text-decoration:underline}\
+nav a{margin-right:0.75em}";
+
+pub fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+/// Describe the event below the page heading, skipping its title
+pub fn event_details(event: &Event) -> String {
+    event
+        .header_lines()
+        .iter()
+        .skip(1)
+        .map(|line| format!("<p class=\"event\">{}</p>\n", escape(line)))
+        .collect()
+}
+
+/// Wrap the body in a complete HTML document
+pub fn page(title: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>{STYLE}</style>\n</head>\n<body>\n<h1>{}</h1>\n{body}</body>\n</html>\n",
+        escape(title),
+        escape(title)
+    )
+}
+
+/// Render a table from pre-escaped header and body cells. Each row carries an optional CSS class.
+pub fn table(header: &[String], rows: &[(Option<&str>, Vec<String>)]) -> String {
+    let mut html = String::from("<table>\n<thead><tr>");
+    header
+        .iter()
+        .for_each(|cell| html.push_str(&format!("<th>{cell}</th>")));
+    html.push_str("</tr></thead>\n<tbody>\n");
+    rows.iter().for_each(|(class, cells)| {
+        match class {
+            Some(class) => html.push_str(&format!("<tr class=\"{class}\">")),
+            None => html.push_str("<tr>"),
+        }
+        cells.iter().for_each(|cell| html.push_str(&format!("<td>{cell}</td>")));
+        html.push_str("</tr>\n");
+    });
+    html.push_str("</tbody>\n</table>\n");
+    html
+}
+
+#[cfg(test)]
+mod test {
+    use crate::services::html::template::{escape, table};
+
+    #[test]
+    fn escapes_markup() {
+        assert_eq!(
+            escape("<b>Tom & \"Jerry's\"</b>"),
+            "&lt;b&gt;Tom &amp; &quot;Jerry&#39;s&quot;&lt;/b&gt;"
+        );
+    }
+
+    #[test]
+    fn renders_table() {
+        assert_eq!(
+            table(
+                &["A".to_string(), "B".to_string()],
+                &[
+                    (Some("trophy"), vec!["1".to_string(), "2".to_string()]),
+                    (None, vec!["3".to_string()])
+                ]
+            ),
+            "<table>\n<thead><tr><th>A</th><th>B</th></tr></thead>\n<tbody>\n\
+<tr class=\"trophy\"><td>1</td><td>2</td></tr>\n<tr><td>3</td></tr>\n</tbody>\n</table>\n"
+        );
+    }
+}
@@ -1,2 +1,7 @@
+pub(crate) mod audit;
 pub(crate) mod calculators;
 pub(crate) mod csv;
+pub(crate) mod html;
+pub(crate) mod pdf;
+pub(crate) mod reports;
+pub(crate) mod tools;
@@ -0,0 +1,233 @@
+/// US Letter, in points
+const PAGE_WIDTH: f32 = 612.;
+const PAGE_HEIGHT: f32 = 792.;
+const MARGIN: f32 = 36.;
+const LEADING: f32 = 1.4;
+
+/// The standard PDF fonts used by the results documents. Standard fonts are built into every viewer, so nothing needs
+/// to be embedded.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Font {
+    Heading,
+    Body,
+    BodyBold,
+}
+
+impl Font {
+    fn resource_name(&self) -> &'static str {
+        match self {
+            Font::Heading => "F1",
+            Font::Body => "F2",
+            Font::BodyBold => "F3",
+        }
+    }
+
+    fn base_font(&self) -> &'static str {
+        match self {
+            Font::Heading => "Helvetica-Bold",
+            Font::Body => "Courier",
+            Font::BodyBold => "Courier-Bold",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Line {
+    pub font: Font,
+    pub size: f32,
+    pub text: String,
+}
+
+impl Line {
+    pub fn new(font: Font, size: f32, text: String) -> Line {
+        Line { font, size, text }
+    }
+
+    pub fn height(&self) -> f32 {
+        self.size * LEADING
+    }
+}
+
+/// A minimal PDF writer that lays out lines of text top to bottom, one list of lines per page
+#[derive(Default)]
+pub struct Document {
+    pages: Vec<Vec<Line>>,
+}
+
+impl Document {
+    /// Vertical space available for lines on a single page
+    pub fn usable_height() -> f32 {
+        PAGE_HEIGHT - 2. * MARGIN
+    }
+
+    /// How many lines of the given size fit in the given height
+    pub fn lines_that_fit(height: f32, size: f32) -> usize {
+        (height / (size * LEADING)).floor() as usize
+    }
+
+    pub fn add_page(&mut self, lines: Vec<Line>) {
+        self.pages.push(lines);
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let fonts = [Font::Heading, Font::Body, Font::BodyBold];
+        // Objects are numbered from 1: catalog, page tree, fonts, then a page and its content stream for each page
+        let first_page_id = 3 + fonts.len();
+        let page_ids = (0..self.pages.len())
+            .map(|i| first_page_id + 2 * i)
+            .collect::<Vec<usize>>();
+
+        let mut objects = vec![
+            "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+            format!(
+                "<< /Type /Pages /Kids [{}] /Count {} >>",
+                page_ids
+                    .iter()
+                    .map(|id| format!("{id} 0 R"))
+                    .collect::<Vec<String>>()
+                    .join(" "),
+                self.pages.len()
+            ),
+        ];
+        objects.extend(fonts.iter().map(|font| {
+            format!(
+                "<< /Type /Font /Subtype /Type1 /BaseFont /{} /Encoding /WinAnsiEncoding >>",
+                font.base_font()
+            )
+        }));
+        let font_resources = fonts
+            .iter()
+            .enumerate()
+            .map(|(i, font)| format!("/{} {} 0 R", font.resource_name(), 3 + i))
+            .collect::<Vec<String>>()
+            .join(" ");
+
+        self.pages.iter().zip(page_ids.iter()).for_each(|(lines, page_id)| {
+            objects.push(format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {PAGE_WIDTH} {PAGE_HEIGHT}] \
+/Resources << /Font << {font_resources} >> >> /Contents {} 0 R >>",
+                page_id + 1
+            ));
+            let content = Self::content_stream(lines);
+            objects.push(format!("<< /Length {} >>\nstream\n{content}\nendstream", content.len()));
+        });
+
+        let mut pdf = String::from("%PDF-1.4\n");
+        let mut offsets = vec![];
+        objects.iter().enumerate().for_each(|(i, object)| {
+            offsets.push(pdf.len());
+            pdf.push_str(&format!("{} 0 obj\n{object}\nendobj\n", i + 1));
+        });
+
+        let xref_offset = pdf.len();
+        pdf.push_str(&format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1));
+        offsets
+            .iter()
+            .for_each(|offset| pdf.push_str(&format!("{offset:010} 00000 n \n")));
+        pdf.push_str(&format!(
+            "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref_offset}\n%%EOF\n",
+            objects.len() + 1
+        ));
+
+        pdf.into_bytes()
+    }
+
+    fn content_stream(lines: &[Line]) -> String {
+        let mut y = PAGE_HEIGHT - MARGIN;
+        lines
+            .iter()
+            .map(|line| {
+                y -= line.height();
+                format!(
+                    "BT /{} {} Tf {MARGIN} {y:.2} Td ({}) Tj ET",
+                    line.font.resource_name(),
+                    line.size,
+                    Self::escape(&line.text)
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    /// PDF string literals must escape their delimiters. Characters beyond ASCII are written as octal escapes of their
+    /// WinAnsiEncoding byte, and anything that encoding can't show is replaced.
+    fn escape(text: &str) -> String {
+        text.chars()
+            .map(|c| match c {
+                '\\' | '(' | ')' => format!("\\{c}"),
+                ' '..='~' => c.to_string(),
+                _ => match Self::win_ansi_byte(c) {
+                    Some(byte) => format!("\\{byte:03o}"),
+                    None => "?".to_string(),
+                },
+            })
+            .collect()
+    }
+
+    /// Latin-1 characters share their byte in WinAnsiEncoding, which also puts typographic punctuation and a few
+    /// letters in 0x80–0x9F
+    fn win_ansi_byte(c: char) -> Option<u8> {
+        match c {
+            '\u{a0}'..='\u{ff}' => Some(c as u8),
+            '\u{20ac}' => Some(0x80),
+            '\u{201a}' => Some(0x82),
+            '\u{192}' => Some(0x83),
+            '\u{201e}' => Some(0x84),
+            '\u{2026}' => Some(0x85),
+            '\u{2020}' => Some(0x86),
+            '\u{2021}' => Some(0x87),
+            '\u{2c6}' => Some(0x88),
+            '\u{2030}' => Some(0x89),
+            '\u{160}' => Some(0x8a),
+            '\u{2039}' => Some(0x8b),
+            '\u{152}' => Some(0x8c),
+            '\u{17d}' => Some(0x8e),
+            '\u{2018}' => Some(0x91),
+            '\u{2019}' => Some(0x92),
+            '\u{201c}' => Some(0x93),
+            '\u{201d}' => Some(0x94),
+            '\u{2022}' => Some(0x95),
+            '\u{2013}' => Some(0x96),
+            '\u{2014}' => Some(0x97),
+            '\u{2dc}' => Some(0x98),
+            '\u{2122}' => Some(0x99),
+            '\u{161}' => Some(0x9a),
+            '\u{203a}' => Some(0x9b),
+            '\u{153}' => Some(0x9c),
+            '\u{17e}' => Some(0x9e),
+            '\u{178}' => Some(0x9f),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::services::pdf::document::{Document, Font, Line};
+
+    #[test]
+    fn writes_valid_cross_reference_table() {
+        let mut document = Document::default();
+        document.add_page(vec![Line::new(Font::Heading, 14., "Event (1)".to_string())]);
+        document.add_page(vec![Line::new(
+            Font::Body,
+            8.,
+            "Jos\u{e9} \u{2013} \u{3a9}".to_string(),
+        )]);
+
+        let actual = String::from_utf8(document.to_bytes()).unwrap();
+
+        assert!(actual.starts_with("%PDF-1.4\n"));
+        assert!(actual.ends_with("%%EOF\n"));
+        assert!(actual.contains("/Count 2"));
+        assert!(actual.contains("(Event \\(1\\)) Tj"));
+        assert!(actual.contains("(Jos\\351 \\226 ?) Tj"));
+
+        let startxref = actual.lines().rev().nth(1).unwrap().parse::<usize>().unwrap();
+        assert!(actual[startxref..].starts_with("xref\n0 10\n"));
+        let first_offset = actual[startxref..].lines().nth(3).unwrap()[..10]
+            .parse::<usize>()
+            .unwrap();
+        assert!(actual[first_offset..].starts_with("1 0 obj\n<< /Type /Catalog"));
+    }
+}
@@ -0,0 +1,216 @@
+use crate::enums::driver_group::DriverGroup;
+use crate::enums::long_car_class::to_display_name;
+use crate::models::event_results::EventResults;
+use crate::services::csv::builder::event::class_results_builder::ClassResultsBuilder;
+use crate::services::csv::builder::event::combined_results_builder::CombinedResultsBuilder;
+use crate::services::pdf::document::{Document, Font, Line};
+
+const TITLE_SIZE: f32 = 14.;
+const SUBTITLE_SIZE: f32 = 11.;
+const TABLE_SIZE: f32 = 8.;
+
+/// Header, width in characters and whether the column is right-aligned
+type Column = (&'static str, usize, bool);
+
+/// Columns line up with [`crate::models::class_result_row::ClassResultRow::to_record()`]
+const CLASS_COLUMNS: [Column; 11] = [
+    ("T", 1, false),
+    ("Pos", 3, true),
+    ("Name", 22, false),
+    ("Car", 24, false),
+    ("Class", 5, false),
+    ("No.", 4, true),
+    ("Time", 8, true),
+    ("Index", 8, true),
+    ("Prev", 7, true),
+    ("Top", 7, true),
+    ("Points", 6, true),
+];
+
+/// Columns line up with [`crate::models::combined_result_row::CombinedResultRow::to_record()`]
+const COMBINED_COLUMNS: [Column; 10] = [
+    ("T", 1, false),
+    ("Pos", 3, true),
+    ("Name", 22, false),
+    ("Car", 24, false),
+    ("Class", 5, false),
+    ("No.", 4, true),
+    ("Time", 8, true),
+    ("Prev", 7, true),
+    ("Top", 7, true),
+    ("Points", 6, true),
+];
+
+/// Render printable results for posting at the event: each class on its own page, followed by the overall PAX and Raw
+/// lists. Trophy positions are marked and set in bold.
+#[derive(Default)]
+pub struct EventResultsPdf {
+    class_results_builder: ClassResultsBuilder,
+    combined_results_builder: CombinedResultsBuilder,
+}
+
+impl EventResultsPdf {
+    pub fn render(&self, results: &EventResults) -> Result<Vec<u8>, String> {
+        let mut document = Document::default();
+        let event_lines = results.event.header_lines();
+
+        results.sorted_class_results().iter().for_each(|class_results| {
+            let rows = self
+                .class_results_builder
+                .build_rows(class_results)
+                .iter()
+                .map(|row| (row.trophy, row.to_record()))
+                .collect::<Vec<(bool, Vec<String>)>>();
+            let section = format!(
+                "{} - {}",
+                class_results.car_class.short.name(),
+                to_display_name(class_results.car_class.long)
+            );
+            Self::add_section(&mut document, &event_lines, &section, &CLASS_COLUMNS, &rows);
+        });
+
+        for group in [DriverGroup::PAX, DriverGroup::Raw] {
+            let rows = self
+                .combined_results_builder
+                .to_combined_rows(results, group)?
+                .iter()
+                .map(|row| (row.trophy, row.to_record()))
+                .collect::<Vec<(bool, Vec<String>)>>();
+            let columns = if group == DriverGroup::Raw {
+                &COMBINED_COLUMNS[..COMBINED_COLUMNS.len() - 1]
+            } else {
+                &COMBINED_COLUMNS[..]
+            };
+            Self::add_section(
+                &mut document,
+                &event_lines,
+                &format!("{} Overall", group.name()),
+                columns,
+                &rows,
+            );
+        }
+
+        Ok(document.to_bytes())
+    }
+
+    /// Add one or more pages for a table, repeating the page header on every page
+    fn add_section(
+        document: &mut Document,
+        event_lines: &[String],
+        section: &str,
+        columns: &[Column],
+        rows: &[(bool, Vec<String>)],
+    ) {
+        let page_header = |continued: bool| {
+            let mut lines = event_lines
+                .iter()
+                .enumerate()
+                .map(|(i, line)| {
+                    Line::new(
+                        Font::Heading,
+                        if i == 0 { TITLE_SIZE } else { SUBTITLE_SIZE },
+                        line.clone(),
+                    )
+                })
+                .collect::<Vec<Line>>();
+            lines.push(Line::new(
+                Font::Heading,
+                SUBTITLE_SIZE,
+                format!("{section}{}", if continued { " (continued)" } else { "" }),
+            ));
+            lines.push(Line::new(Font::Body, TABLE_SIZE, "".to_string()));
+            lines.push(Line::new(
+                Font::BodyBold,
+                TABLE_SIZE,
+                Self::format_row(
+                    columns,
+                    &columns.iter().map(|(h, ..)| h.to_string()).collect::<Vec<String>>(),
+                ),
+            ));
+            lines
+        };
+        let header_height = page_header(false).iter().map(|line| line.height()).sum::<f32>();
+        let rows_per_page = Document::lines_that_fit(Document::usable_height() - header_height, TABLE_SIZE).max(1);
+
+        if rows.is_empty() {
+            let mut lines = page_header(false);
+            lines.push(Line::new(Font::Body, TABLE_SIZE, "No drivers".to_string()));
+            document.add_page(lines);
+            return;
+        }
+
+        rows.chunks(rows_per_page).enumerate().for_each(|(page, chunk)| {
+            let mut lines = page_header(page > 0);
+            lines.extend(chunk.iter().map(|(trophy, cells)| {
+                Line::new(
+                    if *trophy { Font::BodyBold } else { Font::Body },
+                    TABLE_SIZE,
+                    Self::format_row(columns, cells),
+                )
+            }));
+            document.add_page(lines);
+        });
+    }
+
+    /// Pad or truncate each cell to its column width so the table lines up in a fixed-width font
+    fn format_row(columns: &[Column], cells: &[String]) -> String {
+        columns
+            .iter()
+            .zip(cells.iter())
+            .map(|((_, width, right_aligned), cell)| {
+                let cell = cell.chars().take(*width).collect::<String>();
+                if *right_aligned {
+                    format!("{cell:>width$}")
+                } else {
+                    format!("{cell:<width$}")
+                }
+            })
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use crate::enums::class_source::ClassSource;
+    use crate::models::event::Event;
+    use crate::services::csv::parser::event_results_parser::parse;
+    use crate::services::pdf::event_results_pdf::{EventResultsPdf, CLASS_COLUMNS};
+
+    #[test]
+    fn renders_a_page_per_class_and_overall_list() {
+        let sample_contents = fs::read_to_string("./SampleData/2022/2022_Event1-DavidExport.csv").unwrap();
+        let event = Event {
+            date: Some("2022-04-10".to_string()),
+            ..Event::new("Event 1".to_string())
+        };
+        let results = parse(None, sample_contents, ClassSource::Pronto, event).unwrap();
+
+        let actual = String::from_utf8(EventResultsPdf::default().render(&results).unwrap()).unwrap();
+
+        assert!(actual.starts_with("%PDF-1.4"));
+        assert!(actual.contains("/Count 4"));
+        assert!(actual.contains("(Event 1) Tj"));
+        assert!(actual.contains("(2022-04-10) Tj"));
+        assert!(actual.contains("(AS - A Street) Tj"));
+        assert!(actual.contains("(PAX Overall) Tj"));
+        assert!(actual.contains("(Raw Overall) Tj"));
+        assert!(actual.contains("/F3 8 Tf 36 ")); // Trophy rows are bold
+        assert!(actual.contains("(T   1 Robert Fullriede"));
+    }
+
+    #[test]
+    fn pads_and_truncates_cells() {
+        let cells = [
+            "".to_string(),
+            "2".to_string(),
+            "A very long driver name indeed".to_string(),
+        ];
+        assert_eq!(
+            EventResultsPdf::format_row(&CLASS_COLUMNS[..3], &cells),
+            "    2 A very long driver nam"
+        );
+    }
+}
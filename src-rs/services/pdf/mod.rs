@@ -0,0 +1,2 @@
+pub(crate) mod document;
+pub(crate) mod event_results_pdf;
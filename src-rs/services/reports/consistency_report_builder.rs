@@ -0,0 +1,171 @@
+use bigdecimal::ToPrimitive;
+
+use crate::models::class_results::ClassResults;
+use crate::models::consistency_report::{ClassConsistency, ConsistencyReport, DriverConsistency};
+use crate::models::driver::Driver;
+use crate::models::event_results::EventResults;
+use crate::models::lap_time::LapTime;
+
+/// Run counts behind a driver's rates, kept so class rates can be pooled over runs rather than averaged over drivers
+#[derive(Default)]
+struct RunCounts {
+    runs: usize,
+    coned: usize,
+    dnf: usize,
+    within_one_percent: usize,
+}
+
+/// Build run-by-run consistency analytics for every driver at an event, grouped by class
+#[derive(Default)]
+pub struct ConsistencyReportBuilder {}
+
+impl ConsistencyReportBuilder {
+    pub fn build(&self, results: &EventResults) -> ConsistencyReport {
+        let mut drivers = vec![];
+        let mut classes = vec![];
+        results.sorted_class_results().into_iter().for_each(|class_results| {
+            let class_drivers = class_results
+                .drivers
+                .iter()
+                .map(Self::build_driver)
+                .collect::<Vec<(DriverConsistency, RunCounts)>>();
+            classes.push(Self::build_class(class_results, &class_drivers));
+            drivers.extend(class_drivers.into_iter().map(|(driver, _)| driver));
+        });
+        ConsistencyReport { drivers, classes }
+    }
+
+    fn build_driver(driver: &Driver) -> (DriverConsistency, RunCounts) {
+        let taken = driver
+            .times
+            .iter()
+            .filter(|lap| !lap.dns && !lap.rerun)
+            .collect::<Vec<_>>();
+        let seconds = |lap: &&LapTime| lap.time.as_ref().and_then(|t| t.to_f64());
+        let best = taken.iter().filter_map(seconds).reduce(f64::min);
+        let clean = taken
+            .iter()
+            .filter(|lap| lap.penalty().is_none() && lap.cones == 0)
+            .filter_map(seconds)
+            .collect::<Vec<f64>>();
+
+        let counts = RunCounts {
+            runs: taken.len(),
+            coned: taken.iter().filter(|lap| lap.cones > 0).count(),
+            dnf: taken.iter().filter(|lap| lap.dnf).count(),
+            within_one_percent: best
+                .map(|best| taken.iter().filter_map(seconds).filter(|s| *s <= best * 1.01).count())
+                .unwrap_or(0),
+        };
+        (
+            DriverConsistency {
+                driver: driver.name.clone(),
+                car_class: driver.car_class.short,
+                runs: counts.runs,
+                clean_runs: clean.len(),
+                improvement: taken
+                    .first()
+                    .and_then(seconds)
+                    .zip(best)
+                    .map(|(first, best)| round(first - best)),
+                clean_run_std_dev: std_dev(&clean),
+                cone_rate: percentage(counts.coned, counts.runs),
+                dnf_rate: percentage(counts.dnf, counts.runs),
+                within_one_percent: percentage(counts.within_one_percent, counts.runs),
+            },
+            counts,
+        )
+    }
+
+    fn build_class(class_results: &ClassResults, drivers: &[(DriverConsistency, RunCounts)]) -> ClassConsistency {
+        let total = |count: fn(&RunCounts) -> usize| drivers.iter().map(|(_, counts)| count(counts)).sum::<usize>();
+        let runs = total(|c| c.runs);
+        ClassConsistency {
+            car_class: class_results.car_class.short,
+            drivers: drivers.len(),
+            runs,
+            average_improvement: average(drivers.iter().filter_map(|(d, _)| d.improvement)),
+            average_clean_run_std_dev: average(drivers.iter().filter_map(|(d, _)| d.clean_run_std_dev)),
+            cone_rate: percentage(total(|c| c.coned), runs),
+            dnf_rate: percentage(total(|c| c.dnf), runs),
+            within_one_percent: percentage(total(|c| c.within_one_percent), runs),
+        }
+    }
+}
+
+fn round(value: f64) -> f64 {
+    (value * 1000.).round() / 1000.
+}
+
+fn percentage(count: usize, total: usize) -> f64 {
+    if total == 0 {
+        0.
+    } else {
+        (count as f64 * 1000. / total as f64).round() / 10.
+    }
+}
+
+fn average(values: impl Iterator<Item = f64>) -> Option<f64> {
+    let values = values.collect::<Vec<f64>>();
+    if values.is_empty() {
+        None
+    } else {
+        Some(round(values.iter().sum::<f64>() / values.len() as f64))
+    }
+}
+
+/// Population standard deviation, since a driver's runs are the whole population rather than a sample of it
+fn std_dev(values: &[f64]) -> Option<f64> {
+    if values.len() < 2 {
+        return None;
+    }
+    let mean = values.iter().sum::<f64>() / values.len() as f64;
+    let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / values.len() as f64;
+    Some(round(variance.sqrt()))
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use crate::enums::class_source::ClassSource;
+    use crate::enums::short_car_class::ShortCarClass;
+    use crate::models::event::Event;
+    use crate::services::csv::parser::event_results_parser::parse;
+    use crate::services::reports::consistency_report_builder::{std_dev, ConsistencyReportBuilder};
+
+    #[test]
+    fn reports_every_driver_and_class() {
+        let sample_contents = fs::read_to_string("./SampleData/2022/2022_Event1-DavidExport.csv").unwrap();
+        let results = parse(None, sample_contents, ClassSource::Pronto, Event::default()).unwrap();
+
+        let actual = ConsistencyReportBuilder::default().build(&results);
+
+        assert_eq!(actual.drivers.len(), results.get_drivers(None).len());
+        assert_eq!(actual.classes.len(), results.results.len());
+        let driver = actual.drivers.iter().find(|d| d.driver == "Robert Fullriede").unwrap();
+        assert_eq!(driver.car_class, ShortCarClass::AS);
+        assert_eq!(driver.runs, 3);
+        assert_eq!(driver.clean_runs, 2);
+        assert_eq!(driver.improvement, Some(1.063));
+        assert_eq!(driver.clean_run_std_dev, Some(0.532));
+        assert_eq!(driver.dnf_rate, 33.3);
+        assert_eq!(driver.within_one_percent, 33.3);
+        let class = actual
+            .classes
+            .iter()
+            .find(|c| c.car_class == ShortCarClass::AS)
+            .unwrap();
+        assert_eq!(class.drivers, 2);
+        assert!(actual
+            .to_csv()
+            .contains("\"Robert Fullriede\",AS,3,2,1.063,0.532,0.0,33.3,33.3"));
+    }
+
+    #[test]
+    fn std_dev_needs_two_runs() {
+        assert_eq!(std_dev(&[]), None);
+        assert_eq!(std_dev(&[50.]), None);
+        assert_eq!(std_dev(&[50., 52.]), Some(1.));
+    }
+}
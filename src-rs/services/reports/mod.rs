@@ -0,0 +1,52 @@
+use crate::enums::class_source::ClassSource;
+use crate::models::event::Event;
+use crate::models::event_results::EventResults;
+use crate::services::csv::parser::event_results_parser::parse;
+
+pub(crate) mod consistency_report_builder;
+pub(crate) mod normalization_builder;
+pub(crate) mod pax_fairness_builder;
+pub(crate) mod report_card_builder;
+
+/// Parse a season of Pronto exports, given in season order, without MSR data. `events` describes the exports at the
+/// same index; exports past its end are left undescribed.
+pub(crate) fn parse_season(pronto_exports: Vec<String>, events: Vec<Event>) -> Result<Vec<EventResults>, String> {
+    if events.len() > pronto_exports.len() {
+        return Err(format!(
+            "Given details for {} events, but only {} Pronto exports",
+            events.len(),
+            pronto_exports.len()
+        ));
+    }
+    pronto_exports
+        .into_iter()
+        .zip(events.into_iter().chain(std::iter::repeat_with(Event::default)))
+        .map(|(export, event)| parse(None, export, ClassSource::Pronto, event))
+        .collect()
+}
+
+/// The two sample events that parse, a season apart, used by the report tests
+#[cfg(test)]
+pub(crate) const SAMPLE_SEASON: [&str; 2] = ["2022/2022_Event1-DavidExport.csv", "2023/2023_Event3-DavidExport.csv"];
+
+/// Parse sample Pronto exports, given as paths under `SampleData`, as a season
+#[cfg(test)]
+pub(crate) fn sample_season(files: &[&str]) -> Vec<EventResults> {
+    parse_season(
+        files
+            .iter()
+            .map(|file| std::fs::read_to_string(format!("./SampleData/{file}")).unwrap())
+            .collect(),
+        vec![],
+    )
+    .unwrap()
+}
+
+/// How an event is named in season reports: the same label as its championship column, falling back to its place in
+/// the season when it was not named
+pub(crate) fn event_label(results: &EventResults, index: usize) -> String {
+    results
+        .event
+        .column_label()
+        .unwrap_or_else(|| format!("Event #{}", index + 1))
+}
@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+
+use bigdecimal::ToPrimitive;
+use wasm_bindgen::prelude::wasm_bindgen;
+use wasm_bindgen::JsValue;
+
+use crate::models::driver::Driver;
+use crate::models::event::Event;
+use crate::models::event_results::EventResults;
+use crate::models::season_normalization::{EventBaseline, NormalizedDriver, SeasonNormalization};
+use crate::services::reports::{event_label, parse_season};
+
+const BASELINE_DRIVERS: usize = 10;
+
+/// Normalize each driver's PAX time against a per-event baseline: the median of the fastest ten PAX times. The median
+/// keeps one standout run from skewing the baseline, and using the top of the field keeps it independent of how many
+/// novices turned out. Only the [PAX field](EventResults::get_pax_field) is scored.
+#[derive(Default)]
+pub struct NormalizationBuilder {}
+
+impl NormalizationBuilder {
+    pub fn build(&self, season: &[EventResults]) -> SeasonNormalization {
+        let mut names = HashMap::new();
+        let mut scores: HashMap<String, Vec<Option<f64>>> = HashMap::new();
+        let baselines = season
+            .iter()
+            .enumerate()
+            .map(|(index, results)| {
+                let times = Self::pax_times(results);
+                let baseline = Self::median_of_fastest(&times);
+                if let Some(baseline) = baseline {
+                    times.iter().for_each(|(driver, time)| {
+                        names.entry(driver.id.clone()).or_insert_with(|| driver.name.clone());
+                        scores
+                            .entry(driver.id.clone())
+                            .or_insert_with(|| vec![None; season.len()])[index] = Some(score(*time, baseline));
+                    });
+                }
+                EventBaseline {
+                    event: event_label(results, index),
+                    baseline: baseline.map(|b| round(b, 3)),
+                    drivers_in_baseline: times.len().min(BASELINE_DRIVERS),
+                }
+            })
+            .collect();
+
+        let mut drivers = scores
+            .into_iter()
+            .map(|(id, scores)| {
+                let set = scores.iter().flatten().copied().collect::<Vec<f64>>();
+                NormalizedDriver {
+                    driver: names.remove(&id).unwrap_or(id),
+                    average_score: round(set.iter().sum::<f64>() / set.len() as f64, 2),
+                    best_score: set.iter().copied().reduce(f64::min).unwrap_or_default(),
+                    scores,
+                }
+            })
+            .collect::<Vec<NormalizedDriver>>();
+        drivers.sort_by(|lhs, rhs| {
+            lhs.average_score
+                .total_cmp(&rhs.average_score)
+                .then_with(|| lhs.driver.cmp(&rhs.driver))
+        });
+
+        SeasonNormalization { baselines, drivers }
+    }
+
+    /// The event's baseline, if anyone set a time
+    pub fn baseline(&self, results: &EventResults) -> Option<f64> {
+        Self::median_of_fastest(&Self::pax_times(results))
+    }
+
+    /// A driver's score against a baseline from [`NormalizationBuilder::baseline()`]
+    pub fn score(&self, driver: &Driver, baseline: f64) -> Option<f64> {
+        Self::pax_time(driver).map(|time| score(time, baseline))
+    }
+
+    /// The PAX field, fastest first
+    fn pax_times(results: &EventResults) -> Vec<(&Driver, f64)> {
+        results
+            .get_pax_field()
+            .into_iter()
+            .filter_map(|d| Self::pax_time(d).map(|time| (d, time)))
+            .collect()
+    }
+
+    fn pax_time(driver: &Driver) -> Option<f64> {
+        driver.best_standard_lap().with_pax().and_then(|time| time.to_f64())
+    }
+
+    fn median_of_fastest(times: &[(&Driver, f64)]) -> Option<f64> {
+        let fastest = &times[..times.len().min(BASELINE_DRIVERS)];
+        match fastest.len() {
+            0 => None,
+            n if n % 2 == 1 => Some(fastest[n / 2].1),
+            n => Some((fastest[n / 2 - 1].1 + fastest[n / 2].1) / 2.),
+        }
+    }
+}
+
+fn score(time: f64, baseline: f64) -> f64 {
+    round(time / baseline * 100., 2)
+}
+
+fn round(value: f64, places: i32) -> f64 {
+    let scale = 10f64.powi(places);
+    (value * scale).round() / scale
+}
+
+/// See [`NormalizationBuilder::build()`] and [`parse_season()`]
+#[wasm_bindgen]
+pub fn season_normalization(pronto_exports: Vec<String>, events: Vec<Event>) -> Result<JsValue, String> {
+    let normalization = NormalizationBuilder::default().build(&parse_season(pronto_exports, events)?);
+    serde_wasm_bindgen::to_value(&normalization).map_err(|e| e.to_string())
+}
+
+/// Same as [`season_normalization()`], with each event's baseline in the first row
+#[wasm_bindgen]
+pub fn season_normalization_csv(pronto_exports: Vec<String>, events: Vec<Event>) -> Result<String, String> {
+    Ok(NormalizationBuilder::default()
+        .build(&parse_season(pronto_exports, events)?)
+        .to_csv())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::services::reports::normalization_builder::NormalizationBuilder;
+    use crate::services::reports::{sample_season, SAMPLE_SEASON};
+
+    #[test]
+    fn scores_drivers_against_each_event_baseline() {
+        let season = sample_season(&SAMPLE_SEASON);
+
+        let actual = NormalizationBuilder::default().build(&season);
+
+        assert_eq!(actual.baselines.len(), 2);
+        assert_eq!(actual.baselines[0].event, "Event #1");
+        assert_eq!(actual.baselines[0].baseline, Some(44.488));
+        assert_eq!(actual.baselines[0].drivers_in_baseline, 3);
+        let fullriede = actual.drivers.iter().find(|d| d.driver == "Robert Fullriede").unwrap();
+        assert!(fullriede.scores.iter().all(|s| s.is_some()));
+        assert_eq!(fullriede.scores[0], Some(96.49));
+        assert!(actual
+            .drivers
+            .windows(2)
+            .all(|pair| pair[0].average_score <= pair[1].average_score));
+        assert!(actual
+            .to_csv()
+            .starts_with("Driver,Event #1,Event #2,Average Score,Best Score\nBaseline,"));
+    }
+
+    #[test]
+    fn the_median_driver_sets_the_baseline() {
+        let season = sample_season(&SAMPLE_SEASON);
+        let builder = NormalizationBuilder::default();
+        let baseline = builder.baseline(&season[0]).unwrap();
+
+        let actual = season[0]
+            .get_drivers(None)
+            .iter()
+            .map(|d| (d.name.clone(), builder.score(d, baseline)))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            vec![
+                ("Robert Fullriede".to_string(), Some(96.49)),
+                ("Jeffrey Osborn".to_string(), Some(100.)),
+                ("Adam Buffa".to_string(), Some(103.62)),
+            ]
+        );
+    }
+}
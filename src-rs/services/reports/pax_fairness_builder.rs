@@ -0,0 +1,201 @@
+use std::collections::{BTreeMap, HashSet};
+
+use bigdecimal::ToPrimitive;
+use wasm_bindgen::prelude::wasm_bindgen;
+use wasm_bindgen::JsValue;
+
+use crate::enums::short_car_class::ShortCarClass;
+use crate::models::event_results::EventResults;
+use crate::models::pax_fairness::{PaxFairness, PaxFairnessReport};
+use crate::models::type_aliases::DriverId;
+use crate::services::reports::parse_season;
+
+/// One driver's PAX finish at one event
+struct PaxFinish {
+    driver: DriverId,
+    pax_multiplier: f64,
+    rank: usize,
+    entries: usize,
+    gap: f64,
+}
+
+/// Compare how each class and class category placed in PAX over a season, among the
+/// [PAX field](EventResults::get_pax_field) of each event
+#[derive(Default)]
+pub struct PaxFairnessBuilder {}
+
+impl PaxFairnessBuilder {
+    pub fn build(&self, season: &[EventResults]) -> Result<PaxFairnessReport, String> {
+        let mut by_class: BTreeMap<ShortCarClass, Vec<PaxFinish>> = BTreeMap::new();
+        let mut by_category = BTreeMap::new();
+        for results in season {
+            let finishers = results
+                .get_pax_field()
+                .into_iter()
+                .filter_map(|d| {
+                    d.best_standard_lap()
+                        .with_pax()
+                        .and_then(|t| t.to_f64())
+                        .map(|t| (d, t))
+                })
+                .collect::<Vec<_>>();
+            let Some((_, winner)) = finishers.first().copied() else {
+                continue;
+            };
+            let entries = finishers.len();
+            finishers.into_iter().enumerate().for_each(|(index, (driver, time))| {
+                let finish = || PaxFinish {
+                    driver: driver.id.clone(),
+                    pax_multiplier: driver.pax_multiplier.to_f64().unwrap_or(1.),
+                    rank: index + 1,
+                    entries,
+                    gap: (time / winner - 1.) * 100.,
+                };
+                by_class.entry(driver.car_class.short).or_default().push(finish());
+                by_category
+                    .entry(driver.car_class.category)
+                    .or_insert_with(Vec::new)
+                    .push(finish());
+            });
+        }
+
+        let mut gaps = by_class
+            .values()
+            .flatten()
+            .map(|finish| finish.gap)
+            .collect::<Vec<f64>>();
+        if gaps.is_empty() {
+            return Err("No timed PAX results in the given events".to_string());
+        }
+        gaps.sort_by(f64::total_cmp);
+        let field_median_gap = percentile(&gaps, 0.5);
+
+        Ok(PaxFairnessReport {
+            events: season.len(),
+            field_median_gap: round(field_median_gap, 3),
+            classes: by_class
+                .iter()
+                .map(|(class, finishes)| {
+                    let pax_multiplier = finishes.last().map(|finish| finish.pax_multiplier);
+                    Self::build_group(class.name().to_string(), pax_multiplier, finishes, field_median_gap)
+                })
+                .collect(),
+            categories: by_category
+                .iter()
+                .map(|(category, finishes)| {
+                    Self::build_group(category.name().replace('_', " "), None, finishes, field_median_gap)
+                })
+                .collect(),
+        })
+    }
+
+    fn build_group(
+        group: String,
+        pax_multiplier: Option<f64>,
+        finishes: &[PaxFinish],
+        field_median_gap: f64,
+    ) -> PaxFairness {
+        let mut gaps = finishes.iter().map(|finish| finish.gap).collect::<Vec<f64>>();
+        gaps.sort_by(f64::total_cmp);
+        let median_gap = percentile(&gaps, 0.5);
+        let count = finishes.len() as f64;
+        let suggested_adjustment = (1. + field_median_gap / 100.) / (1. + median_gap / 100.);
+
+        PaxFairness {
+            group,
+            pax_multiplier,
+            results: finishes.len(),
+            drivers: finishes
+                .iter()
+                .map(|finish| &finish.driver)
+                .collect::<HashSet<_>>()
+                .len(),
+            average_pax_rank: round(finishes.iter().map(|f| f.rank as f64).sum::<f64>() / count, 1),
+            average_pax_percentile: round(
+                finishes
+                    .iter()
+                    .map(|f| f.rank as f64 * 100. / f.entries as f64)
+                    .sum::<f64>()
+                    / count,
+                1,
+            ),
+            best_gap: round(gaps[0], 3),
+            first_quartile_gap: round(percentile(&gaps, 0.25), 3),
+            median_gap: round(median_gap, 3),
+            third_quartile_gap: round(percentile(&gaps, 0.75), 3),
+            worst_gap: round(gaps[gaps.len() - 1], 3),
+            suggested_adjustment: round(suggested_adjustment, 4),
+            suggested_pax_multiplier: pax_multiplier.map(|m| round(m * suggested_adjustment, 3)),
+        }
+    }
+}
+
+/// Linearly interpolated percentile of sorted, non-empty values
+fn percentile(sorted: &[f64], fraction: f64) -> f64 {
+    let position = (sorted.len() - 1) as f64 * fraction;
+    let lower = position.floor() as usize;
+    let upper = position.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (position - lower as f64)
+}
+
+fn round(value: f64, places: i32) -> f64 {
+    let scale = 10f64.powi(places);
+    (value * scale).round() / scale
+}
+
+/// See [`PaxFairnessBuilder::build()`] and [`parse_season()`]
+#[wasm_bindgen]
+pub fn pax_fairness(pronto_exports: Vec<String>) -> Result<JsValue, String> {
+    let report = PaxFairnessBuilder::default().build(&parse_season(pronto_exports, vec![])?)?;
+    serde_wasm_bindgen::to_value(&report).map_err(|e| e.to_string())
+}
+
+/// Same as [`pax_fairness()`], as a class table followed by a category table
+#[wasm_bindgen]
+pub fn pax_fairness_csv(pronto_exports: Vec<String>) -> Result<String, String> {
+    Ok(PaxFairnessBuilder::default()
+        .build(&parse_season(pronto_exports, vec![])?)?
+        .to_csv())
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use crate::services::reports::pax_fairness_builder::{percentile, PaxFairnessBuilder};
+    use crate::services::reports::{sample_season, SAMPLE_SEASON};
+
+    #[test]
+    fn compares_every_class_and_category_to_the_field() {
+        let season = sample_season(&SAMPLE_SEASON);
+
+        let actual = PaxFairnessBuilder::default().build(&season).unwrap();
+
+        assert_eq!(actual.events, 2);
+        let class = actual.classes.iter().find(|c| c.group == "AS").unwrap();
+        assert_eq!(class.pax_multiplier, Some(0.823)); // The 2023 index
+        assert_eq!(class.results, 4);
+        assert_eq!(class.drivers, 3);
+        assert!(class.best_gap <= class.median_gap && class.median_gap <= class.worst_gap);
+        assert!(actual.classes.iter().any(|c| c.best_gap == 0.)); // Each PAX winner's class
+        assert_eq!(
+            actual.classes.iter().map(|c| c.results).sum::<usize>(),
+            actual.categories.iter().map(|c| c.results).sum::<usize>()
+        );
+        assert!(actual.to_csv().contains("\nCategory,PAX Multiplier,"));
+    }
+
+    #[test]
+    fn empty_season_is_an_error() {
+        assert!(PaxFairnessBuilder::default().build(&[]).is_err());
+    }
+
+    #[rstest]
+    #[case(0., 1.)]
+    #[case(0.25, 1.75)]
+    #[case(0.5, 2.5)]
+    #[case(1., 4.)]
+    fn interpolates_percentiles(#[case] fraction: f64, #[case] expected: f64) {
+        assert_eq!(percentile(&[1., 2., 3., 4.], fraction), expected);
+    }
+}
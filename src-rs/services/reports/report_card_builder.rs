@@ -0,0 +1,291 @@
+use std::collections::BTreeMap;
+
+use wasm_bindgen::prelude::wasm_bindgen;
+use wasm_bindgen::JsValue;
+
+use crate::enums::championship_type::ChampionshipType;
+use crate::enums::driver_group::DriverGroup;
+use crate::enums::short_car_class::ShortCarClass;
+use crate::models::driver::Driver;
+use crate::models::event::Event;
+use crate::models::event_results::EventResults;
+use crate::models::report_card::{ReportCard, ReportCardChampionship, ReportCardEvent};
+use crate::services::csv::builder::event::class_results_builder::ClassResultsBuilder;
+use crate::services::csv::builder::event::combined_results_builder::CombinedResultsBuilder;
+use crate::services::html::report_card_page::ReportCardPage;
+use crate::services::reports::normalization_builder::NormalizationBuilder;
+use crate::services::reports::{event_label, parse_season};
+use crate::utilities::events_to_count;
+
+type ChampionshipKey = (ChampionshipType, Option<ShortCarClass>);
+
+/// Build a driver's season report card from every event held so far, in season order. Points are calculated the same
+/// way as the event results, so they match what each championship awarded.
+#[derive(Default)]
+pub struct ReportCardBuilder {
+    class_results_builder: ClassResultsBuilder,
+    combined_results_builder: CombinedResultsBuilder,
+    normalization_builder: NormalizationBuilder,
+}
+
+impl ReportCardBuilder {
+    pub fn build(&self, driver_name: &str, season: &[EventResults]) -> Result<ReportCard, String> {
+        let driver_id = driver_name.trim().to_lowercase();
+        let mut name = None;
+        let mut events = vec![];
+        let mut points: BTreeMap<ChampionshipKey, Vec<Option<i64>>> = BTreeMap::new();
+
+        for (index, results) in season.iter().enumerate() {
+            let label = event_label(results, index);
+            let driver = results.get_drivers(None).into_iter().find(|d| d.id == driver_id);
+            match driver {
+                None => events.push(ReportCardEvent {
+                    event: label,
+                    attended: false,
+                    car_class: None,
+                    class_position: None,
+                    class_entries: 0,
+                    pax_position: None,
+                    pax_entries: 0,
+                    gap_to_class_leader: None,
+                    normalized_score: None,
+                }),
+                Some(driver) => {
+                    name.get_or_insert_with(|| driver.name.clone());
+                    let (event, event_points) = self.build_event(label, driver, results)?;
+                    events.push(event);
+                    event_points.into_iter().for_each(|(key, event_points)| {
+                        points.entry(key).or_insert_with(|| vec![None; season.len()])[index] = Some(event_points);
+                    });
+                }
+            }
+        }
+
+        let name = name.ok_or_else(|| format!("{} did not drive in any of the given events", driver_name.trim()))?;
+        let events_to_count = events_to_count(season.len());
+        Ok(ReportCard {
+            driver: name,
+            events,
+            championships: points
+                .into_iter()
+                .map(|((championship_type, car_class), points)| {
+                    Self::build_championship(championship_type, car_class, points, events_to_count)
+                })
+                .collect(),
+        })
+    }
+
+    fn build_event(
+        &self,
+        label: String,
+        driver: &Driver,
+        results: &EventResults,
+    ) -> Result<(ReportCardEvent, Vec<(ChampionshipKey, i64)>), String> {
+        let mut event_points = vec![];
+
+        let class = driver.car_class.short;
+        let class_results = results
+            .results
+            .get(&class)
+            .ok_or_else(|| format!("Missing results for class {}", class.name()))?;
+        let class_row = class_results
+            .drivers
+            .iter()
+            .position(|d| d.id == driver.id)
+            .map(|index| self.class_results_builder.build_rows(class_results).swap_remove(index));
+        if let Some(class_row) = class_row.as_ref() {
+            if class != ShortCarClass::FUN && !driver.dsq {
+                event_points.push(((ChampionshipType::Class, Some(class)), class_row.points));
+            }
+        }
+
+        let mut pax_position = None;
+        let mut pax_entries = 0;
+        for group in [DriverGroup::PAX, DriverGroup::Novice, DriverGroup::Ladies] {
+            let index = results.get_drivers(Some(group)).iter().position(|d| d.id == driver.id);
+            let Some(index) = index else { continue };
+            let rows = self.combined_results_builder.to_combined_rows(results, group)?;
+            if group == DriverGroup::PAX {
+                pax_position = rows.get(index).map(|row| row.position);
+                pax_entries = rows.len();
+            }
+            if let (Some(points), Some(championship_type)) = (
+                rows.get(index).and_then(|row| row.points),
+                ChampionshipType::from(group),
+            ) {
+                if class != ShortCarClass::FUN && !driver.dsq {
+                    event_points.push(((championship_type, None), points));
+                }
+            }
+        }
+
+        Ok((
+            ReportCardEvent {
+                event: label,
+                attended: true,
+                car_class: Some(class),
+                class_position: class_row.as_ref().and_then(|row| row.position),
+                class_entries: class_results.drivers.len(),
+                pax_position,
+                pax_entries,
+                gap_to_class_leader: class_row.map(|row| row.from_top),
+                normalized_score: self
+                    .normalization_builder
+                    .baseline(results)
+                    .and_then(|baseline| self.normalization_builder.score(driver, baseline)),
+            },
+            event_points,
+        ))
+    }
+
+    /// The best `events_to_count` results count, the rest are dropped. Ties go to the earlier event.
+    fn build_championship(
+        championship_type: ChampionshipType,
+        car_class: Option<ShortCarClass>,
+        points: Vec<Option<i64>>,
+        events_to_count: usize,
+    ) -> ReportCardChampionship {
+        let mut order = (0..points.len()).collect::<Vec<usize>>();
+        order.sort_by_key(|index| (std::cmp::Reverse(points[*index].unwrap_or(0)), *index));
+        let counted = &order[..events_to_count.min(order.len())];
+
+        ReportCardChampionship {
+            championship_type,
+            car_class,
+            counted_total: counted.iter().map(|index| points[*index].unwrap_or(0)).sum(),
+            best_event: order
+                .first()
+                .filter(|index| points[**index].is_some())
+                .map(|index| index + 1),
+            worst_counted_event: counted.last().map(|index| index + 1),
+            dropped_events: (0..points.len())
+                .filter(|index| !counted.contains(index))
+                .map(|index| index + 1)
+                .collect(),
+            events_to_count,
+            points,
+        }
+    }
+}
+
+/// See [`ReportCardBuilder::build()`] and [`parse_season()`]
+#[wasm_bindgen]
+pub fn driver_report_card(
+    pronto_exports: Vec<String>,
+    events: Vec<Event>,
+    driver_name: String,
+) -> Result<JsValue, String> {
+    let report_card = ReportCardBuilder::default().build(&driver_name, &parse_season(pronto_exports, events)?)?;
+    serde_wasm_bindgen::to_value(&report_card).map_err(|e| e.to_string())
+}
+
+/// Same as [`driver_report_card()`], rendered as a self-contained HTML page
+#[wasm_bindgen]
+pub fn driver_report_card_html(
+    pronto_exports: Vec<String>,
+    events: Vec<Event>,
+    driver_name: String,
+) -> Result<String, String> {
+    let report_card = ReportCardBuilder::default().build(&driver_name, &parse_season(pronto_exports, events)?)?;
+    Ok(ReportCardPage::default().render(&report_card))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::enums::championship_type::ChampionshipType;
+    use crate::enums::short_car_class::ShortCarClass;
+    use crate::models::event::Event;
+    use crate::services::reports::report_card_builder::ReportCardBuilder;
+    use crate::services::reports::{parse_season, sample_season, SAMPLE_SEASON};
+
+    #[test]
+    fn reports_every_event_and_championship() {
+        let season = sample_season(&SAMPLE_SEASON);
+
+        let actual = ReportCardBuilder::default().build("robert fullriede", &season).unwrap();
+
+        assert_eq!(actual.driver, "Robert Fullriede");
+        assert_eq!(actual.events.len(), 2);
+        assert!(actual.events.iter().all(|e| e.attended));
+        assert_eq!(actual.events[0].event, "Event #1");
+        assert_eq!(actual.events[0].class_position, Some(1));
+        assert_eq!(actual.events[0].class_entries, 2);
+        assert_eq!(actual.events[0].gap_to_class_leader.as_ref().unwrap().display, "");
+        assert_eq!(actual.events[0].normalized_score, Some(96.49));
+
+        let class = actual
+            .championships
+            .iter()
+            .find(|c| c.championship_type == ChampionshipType::Class)
+            .unwrap();
+        assert_eq!(class.car_class, Some(ShortCarClass::AS));
+        assert_eq!(class.points[0], Some(10_000));
+        assert_eq!(class.events_to_count, 2);
+        assert!(class.dropped_events.is_empty());
+        assert!(actual
+            .championships
+            .iter()
+            .any(|c| c.championship_type == ChampionshipType::PAX));
+    }
+
+    #[test]
+    fn labels_events_like_championship_columns() {
+        let exports = SAMPLE_SEASON
+            .iter()
+            .map(|file| std::fs::read_to_string(format!("./SampleData/{file}")).unwrap())
+            .collect();
+        let spring_opener = Event {
+            date: Some("2022-04-10".to_string()),
+            ..Event::new("Spring Opener".to_string())
+        };
+        let season = parse_season(exports, vec![spring_opener]).unwrap();
+
+        let actual = ReportCardBuilder::default().build("robert fullriede", &season).unwrap();
+
+        assert_eq!(actual.events[0].event, "Spring Opener (2022-04-10)");
+        assert_eq!(actual.events[1].event, "Event #2");
+    }
+
+    #[test]
+    fn more_event_details_than_exports_is_an_error() {
+        let actual = parse_season(vec![], vec![Event::new("Spring Opener".to_string())]);
+
+        assert_eq!(
+            actual.err(),
+            Some("Given details for 1 events, but only 0 Pronto exports".to_string())
+        );
+    }
+
+    #[test]
+    fn missed_events_are_dropped_first() {
+        let season = sample_season(&[
+            "2022/2022_Event1-DavidExport.csv",
+            "2022/2022_Event1-DavidExport.csv",
+            "2022/2022_Event1-DavidExport.csv",
+            "2023/2023_Event3-DavidExport.csv",
+        ]);
+
+        let actual = ReportCardBuilder::default().build("Adam Buffa", &season).unwrap();
+
+        let class = &actual.championships[0];
+        assert_eq!(class.championship_type, ChampionshipType::Class);
+        assert_eq!(class.events_to_count, 3);
+        assert_eq!(class.points[..3], [Some(10_000), Some(10_000), Some(10_000)]);
+        assert_eq!(class.counted_total, 30_000);
+        assert_eq!(class.best_event, Some(1));
+        assert_eq!(class.worst_counted_event, Some(3));
+        assert_eq!(class.dropped_events, vec![4]);
+    }
+
+    #[test]
+    fn unknown_driver_is_an_error() {
+        let season = sample_season(&["2022/2022_Event1-DavidExport.csv"]);
+
+        let actual = ReportCardBuilder::default().build("Nobody", &season);
+
+        assert_eq!(
+            actual,
+            Err("Nobody did not drive in any of the given events".to_string())
+        );
+    }
+}
@@ -0,0 +1,62 @@
+use std::collections::HashMap;
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::enums::class_source::ClassSource;
+use crate::models::event::Event;
+use crate::models::event_results::EventResults;
+use crate::models::type_aliases::DriverId;
+use crate::services::csv::parser::event_results_parser::parse;
+
+/// Names of every driver that recorded a time in at least one of the given events, sorted and counted once each
+pub fn unique_drivers(events: &[EventResults]) -> Vec<String> {
+    let mut names_by_id: HashMap<DriverId, String> = HashMap::new();
+    events
+        .iter()
+        .flat_map(|event| event.get_drivers(None))
+        .filter(|driver| driver.best_standard_lap().time.is_some())
+        .for_each(|driver| {
+            names_by_id
+                .entry(driver.id.clone())
+                .or_insert_with(|| driver.name.clone());
+        });
+
+    let mut names = names_by_id.into_values().collect::<Vec<String>>();
+    names.sort();
+    names
+}
+
+/// See [`unique_drivers()`]. Each export is parsed from Pronto data alone.
+#[wasm_bindgen]
+pub fn unique_driver_names(pronto_exports: Vec<String>) -> Result<Vec<String>, String> {
+    let events = pronto_exports
+        .into_iter()
+        .map(|export| parse(None, export, ClassSource::Pronto, Event::default()))
+        .collect::<Result<Vec<EventResults>, String>>()?;
+    Ok(unique_drivers(&events))
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use crate::services::tools::driver_count::unique_driver_names;
+
+    #[test]
+    fn counts_each_driver_once() {
+        let event_1 = fs::read_to_string("./SampleData/2022/2022_Event1-DavidExport.csv").unwrap();
+        let event_3 = fs::read_to_string("./SampleData/2023/2023_Event3-DavidExport.csv").unwrap();
+
+        let actual = unique_driver_names(vec![event_1, event_3]).unwrap();
+
+        assert_eq!(
+            actual,
+            vec![
+                "Adam Buffa".to_string(),
+                "Adrian Sweetwood".to_string(),
+                "Jeffrey Osborn".to_string(),
+                "Robert Fullriede".to_string(),
+            ]
+        );
+    }
+}
@@ -0,0 +1,233 @@
+use std::collections::BTreeMap;
+
+use wasm_bindgen::prelude::wasm_bindgen;
+use wasm_bindgen::JsValue;
+
+use crate::models::heat_roster::{Heat, HeatEntry, HeatRoster};
+use crate::models::msr_driver::MsrDriver;
+use crate::services::csv::parser::event_results_parser::parse_msr;
+
+/// Split registered drivers into `heat_count` heats. Each class runs together, and classes are placed largest first
+/// into whichever heat has the fewest drivers so far. Co-drivers never share a heat: of each pair, the driver with the
+/// higher car number is moved out of the class's heat, into the smallest heat none of their co-drivers are in.
+pub fn schedule_heats(drivers: &[MsrDriver], heat_count: usize) -> Result<HeatRoster, String> {
+    if heat_count == 0 {
+        return Err("At least one heat is needed".to_string());
+    }
+    let mut drivers = drivers.iter().collect::<Vec<&MsrDriver>>();
+    drivers.sort_by_key(|d| (class_name(d), d.car_number, d.id()));
+
+    let co_drivers = drivers
+        .iter()
+        .map(|d| {
+            drivers
+                .iter()
+                .enumerate()
+                .filter(|(_, other)| d.is_co_driver_of(other))
+                .map(|(index, _)| index)
+                .collect::<Vec<usize>>()
+        })
+        .collect::<Vec<Vec<usize>>>();
+    let moved = (0..drivers.len())
+        .map(|i| co_drivers[i].iter().any(|j| moves_over(&drivers, i, *j)))
+        .collect::<Vec<bool>>();
+
+    let mut classes: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+    (0..drivers.len())
+        .filter(|i| !moved[*i])
+        .for_each(|i| classes.entry(class_name(drivers[i])).or_default().push(i));
+    let mut classes = classes.into_iter().collect::<Vec<(String, Vec<usize>)>>();
+    classes.sort_by_key(|(name, members)| (std::cmp::Reverse(members.len()), name.clone()));
+
+    let mut heats: Vec<(Vec<String>, Vec<usize>)> = vec![(vec![], vec![]); heat_count];
+    let mut assigned: Vec<Option<usize>> = vec![None; drivers.len()];
+    for (name, members) in classes {
+        let heat = smallest(&heats, |_| true);
+        members.iter().for_each(|i| assigned[*i] = Some(heat));
+        heats[heat].0.push(name);
+        heats[heat].1.extend(members);
+    }
+    for i in (0..drivers.len()).filter(|i| moved[*i]) {
+        let partner_heats = co_drivers[i]
+            .iter()
+            .filter_map(|j| assigned[*j])
+            .collect::<Vec<usize>>();
+        if partner_heats.len() >= heat_count {
+            return Err(format!(
+                "Not enough heats to separate {} from every co-driver",
+                name(drivers[i])
+            ));
+        }
+        let heat = smallest(&heats, |heat| !partner_heats.contains(&heat));
+        assigned[i] = Some(heat);
+        heats[heat].1.push(i);
+    }
+
+    Ok(HeatRoster {
+        heats: heats
+            .into_iter()
+            .enumerate()
+            .map(|(index, (classes, mut members))| {
+                members.sort();
+                Heat {
+                    number: index + 1,
+                    works_heat: (index + 1) % heat_count + 1,
+                    classes,
+                    entries: members
+                        .into_iter()
+                        .map(|i| HeatEntry {
+                            driver: name(drivers[i]),
+                            car_class: class_name(drivers[i]),
+                            car_number: drivers[i].car_number,
+                            car: drivers[i].car.trim().to_string(),
+                            co_drivers: co_drivers[i].iter().map(|j| name(drivers[*j])).collect(),
+                        })
+                        .collect(),
+                }
+            })
+            .collect(),
+    })
+}
+
+/// Whether the `i`th driver is the one of a co-driver pair to leave the class's heat
+fn moves_over(drivers: &[&MsrDriver], i: usize, j: usize) -> bool {
+    (drivers[i].car_number, i) > (drivers[j].car_number, j)
+}
+
+/// Index of the heat with the fewest drivers, among those allowed, preferring earlier heats
+fn smallest(heats: &[(Vec<String>, Vec<usize>)], allowed: impl Fn(usize) -> bool) -> usize {
+    (0..heats.len())
+        .filter(|heat| allowed(*heat))
+        .min_by_key(|heat| (heats[*heat].1.len(), *heat))
+        .unwrap_or(0)
+}
+
+/// Pro drivers run together in the Pro class, whatever their PAX class
+fn class_name(driver: &MsrDriver) -> String {
+    driver
+        .car_classes()
+        .map(|(car_class, _)| car_class.name().to_string())
+        .unwrap_or_else(|| driver.class_and_pax.trim().to_string())
+}
+
+fn name(driver: &MsrDriver) -> String {
+    format!("{} {}", driver.first_name.trim(), driver.last_name.trim())
+}
+
+/// See [`schedule_heats()`]
+#[wasm_bindgen]
+pub fn heat_roster(msr_export: String, heat_count: usize) -> Result<JsValue, String> {
+    let drivers = parse_msr(msr_export)?.into_values().collect::<Vec<MsrDriver>>();
+    serde_wasm_bindgen::to_value(&schedule_heats(&drivers, heat_count)?).map_err(|e| e.to_string())
+}
+
+/// Same as [`heat_roster()`], one row per driver in heat order
+#[wasm_bindgen]
+pub fn heat_roster_csv(msr_export: String, heat_count: usize) -> Result<String, String> {
+    let drivers = parse_msr(msr_export)?.into_values().collect::<Vec<MsrDriver>>();
+    Ok(schedule_heats(&drivers, heat_count)?.to_csv())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::models::msr_driver::MsrDriver;
+    use crate::services::csv::parser::event_results_parser::parse_msr;
+    use crate::services::tools::heat_scheduler::schedule_heats;
+
+    // language=CSV
+    const MSR_DATA: &str = r###""Last Name","First Name","Member #","Class + Modifier/PAX","No.","Vehicle Year/Make/Model/Color","Region of Record Abbreviation","Medical condition? (Optional)","Is a pro","Novice","Ladies"
+Fullriede,Robert,1,AS,52,2010 Porsche Cayman Black,STL,,0,0,0
+Osborn,Jeffrey,2,AS,77,2015 Subaru BRZ White,STL,,0,0,0
+Fullriede,Jane,3,AS,152,2010 Porsche Cayman Black,STL,,0,0,0
+Buffa,Adam,4,BS,5,2019 Mazda Miata Red,STL,,0,0,0
+Smith,Sam,5,BS,6,2016 Mazda Miata Blue,STL,,0,0,0
+Jones,Pat,6,PBS,7,2020 Toyota Supra Gray,STL,,1,0,0
+Doe,Lee,7,CS,8,2012 Honda Fit Orange,STL,,0,0,0"###;
+
+    fn drivers() -> Vec<MsrDriver> {
+        parse_msr(MSR_DATA.to_string()).unwrap().into_values().collect()
+    }
+
+    #[test]
+    fn keeps_classes_together_and_balances_heats() {
+        let roster = schedule_heats(&drivers(), 2).unwrap();
+
+        let heats = roster
+            .heats
+            .iter()
+            .map(|heat| (heat.number, heat.works_heat, heat.classes.clone(), heat.entries.len()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            heats,
+            vec![
+                (1, 2, vec!["AS".to_string(), "CS".to_string()], 3),
+                (2, 1, vec!["BS".to_string(), "P".to_string()], 4),
+            ]
+        );
+    }
+
+    #[test]
+    fn co_drivers_never_share_a_heat() {
+        let roster = schedule_heats(&drivers(), 2).unwrap();
+
+        let heat_of = |driver: &str| {
+            roster
+                .heats
+                .iter()
+                .find(|heat| heat.entries.iter().any(|e| e.driver == driver))
+                .unwrap()
+                .number
+        };
+        assert_eq!(heat_of("Robert Fullriede"), 1);
+        assert_eq!(heat_of("Jane Fullriede"), 2);
+        assert!(roster
+            .to_csv()
+            .contains("2,1,AS,152,\"Jane Fullriede\",\"2010 Porsche Cayman Black\",\"Robert Fullriede\""));
+    }
+
+    #[test]
+    fn co_drivers_need_more_than_one_heat() {
+        assert!(schedule_heats(&drivers(), 1).is_err());
+        assert!(schedule_heats(&drivers(), 0).is_err());
+    }
+
+    #[test]
+    fn registered_co_drivers_are_split_up() {
+        // language=CSV
+        let msr_data = r###""Last Name","First Name","Member #","Class + Modifier/PAX","No.","Vehicle Year/Make/Model/Color","Region of Record Abbreviation","Medical condition? (Optional)","Is a pro","Novice","Ladies","Co Driver Number"
+Greer,Sean,1,SS,78,Dodge Challenger,STL,,0,0,0,
+Greer,Pat,2,SS,7,Red Dodge Challenger,STL,,0,0,0,78"###;
+        let drivers = parse_msr(msr_data.to_string())
+            .unwrap()
+            .into_values()
+            .collect::<Vec<MsrDriver>>();
+
+        let roster = schedule_heats(&drivers, 2).unwrap();
+
+        assert_eq!(roster.heats[0].entries[0].driver, "Pat Greer");
+        assert_eq!(roster.heats[1].entries[0].driver, "Sean Greer");
+    }
+
+    #[test]
+    fn unrelated_cars_100_apart_stay_together() {
+        // language=CSV
+        let msr_data = r###""Last Name","First Name","Member #","Class + Modifier/PAX","No.","Vehicle Year/Make/Model/Color","Region of Record Abbreviation","Medical condition? (Optional)","Is a pro","Novice","Ladies"
+Buffa,Adam,1,BS,5,2019 Mazda Miata Red,STL,,0,0,0
+Smith,Sam,2,BS,105,2016 Honda S2000 Silver,STL,,0,0,0
+Doe,Lee,3,CS,8,2012 Honda Fit Orange,STL,,0,0,0"###;
+        let drivers = parse_msr(msr_data.to_string())
+            .unwrap()
+            .into_values()
+            .collect::<Vec<MsrDriver>>();
+
+        let roster = schedule_heats(&drivers, 2).unwrap();
+
+        let bs = roster
+            .heats
+            .iter()
+            .find(|heat| heat.classes.contains(&"BS".to_string()))
+            .unwrap();
+        assert_eq!(bs.entries.len(), 2);
+        assert!(bs.entries.iter().all(|e| e.co_drivers.is_empty()));
+    }
+}
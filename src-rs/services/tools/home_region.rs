@@ -0,0 +1,49 @@
+use crate::models::event_results::EventResults;
+use crate::models::region_policy::RegionPolicy;
+
+/// Mark every driver (including no-shows) from outside the home regions as a visitor, clearing the mark from everyone
+/// else. Returns the number of visitors.
+pub fn mark_visitors(results: &mut EventResults, policy: &RegionPolicy) -> usize {
+    results
+        .results
+        .values_mut()
+        .flat_map(|class_results| class_results.drivers.iter_mut())
+        .chain(results.no_shows.iter_mut())
+        .filter_map(|driver| {
+            driver.visitor = policy.is_visitor(&driver.region);
+            driver.visitor.then_some(())
+        })
+        .count()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::enums::short_car_class::ShortCarClass;
+    use crate::enums::visitor_policy::VisitorPolicy;
+    use crate::models::event::Event;
+    use crate::models::region_policy::RegionPolicy;
+    use crate::services::csv::parser::event_results_parser::sample_event_with_msr;
+    use crate::services::tools::home_region::mark_visitors;
+
+    #[test]
+    fn visitors_are_dropped_from_a_championship_copy() {
+        let mut results = sample_event_with_msr(["1", "2", "3"], ["KC", "STL", "STL"], Event::default());
+
+        let visitors = mark_visitors(
+            &mut results,
+            &RegionPolicy::new(vec!["STL".to_string()], VisitorPolicy::Exclude),
+        );
+
+        assert_eq!(visitors, 1);
+        let a_street = &results.results[&ShortCarClass::AS];
+        assert!(a_street.drivers[0].visitor);
+        assert!(!a_street.drivers[1].visitor);
+
+        let home = results.without(|d| d.visitor);
+        let a_street = &home.results[&ShortCarClass::AS];
+        assert_eq!(a_street.drivers.len(), 1);
+        assert_eq!(a_street.drivers[0].name, "Jeffrey Osborn");
+        assert_eq!(a_street.drivers[0].position, Some(1));
+        assert_eq!(home.results[&ShortCarClass::BS].drivers.len(), 1);
+    }
+}
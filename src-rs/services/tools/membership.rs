@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+
+use csv::Trim;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::enums::membership_status::MembershipStatus;
+use crate::models::driver::Driver;
+use crate::models::event_results::EventResults;
+use crate::models::membership::{MembershipCheck, MembershipRules, RosterMember};
+
+lazy_static! {
+    /// Digits with an optional family-member suffix, such as `180831_3`. A few older numbers carry a letter prefix.
+    static ref MEMBER_NUMBER: Regex = Regex::new(r"^[A-Za-z]?\d{1,8}(?:[_-]\d{1,2})?$").unwrap();
+    static ref US_DATE: Regex = Regex::new(r"^(\d{1,2})/(\d{1,2})/(\d{4})$").unwrap();
+    static ref ISO_DATE: Regex = Regex::new(r"^\d{4}-\d{2}-\d{2}$").unwrap();
+}
+
+pub fn is_valid_member_number(member_number: &str) -> bool {
+    MEMBER_NUMBER.is_match(member_number.trim())
+}
+
+/// Member numbers are matched ignoring case, with `-` and `_` treated alike for family-member suffixes
+fn normalize_member_number(member_number: &str) -> String {
+    member_number.trim().to_uppercase().replace('-', "_")
+}
+
+/// `YYYY-MM-DD` for either an ISO or a US `MM/DD/YYYY` date, so that dates compare as strings
+fn iso_date(date: &str) -> Option<String> {
+    let date = date.trim();
+    if ISO_DATE.is_match(date) {
+        return Some(date.to_string());
+    }
+    US_DATE
+        .captures(date)
+        .map(|c| format!("{}-{:0>2}-{:0>2}", &c[3], &c[1], &c[2]))
+}
+
+/// Names match ignoring case and spacing, and `Last, First` matches `First Last`
+fn same_name(roster_name: &str, driver: &Driver) -> bool {
+    let roster_name = match roster_name.split_once(',') {
+        Some((last, first)) => format!("{first} {last}"),
+        None => roster_name.to_string(),
+    };
+    let normalize = |name: &str| name.split_whitespace().collect::<Vec<&str>>().join(" ").to_lowercase();
+    normalize(&roster_name) == normalize(&driver.name)
+}
+
+/// Roster members by normalized member number, with expiry dates in ISO form. Fails on an expiry that isn't a date,
+/// rather than letting the member pass unchecked.
+pub fn parse_roster(roster_export: String) -> Result<HashMap<String, RosterMember>, String> {
+    let mut reader = csv::ReaderBuilder::new()
+        .flexible(true)
+        .trim(Trim::All)
+        .from_reader(roster_export.as_bytes());
+
+    let mut roster = HashMap::new();
+    for record in reader.deserialize() {
+        let mut member: RosterMember = record.map_err(|e| format!("Failed to parse roster member due to {e:?}"))?;
+        member.expires = iso_date(&member.expires).ok_or_else(|| {
+            format!(
+                "Expiry '{}' for member {} is not a date",
+                member.expires, member.member_number
+            )
+        })?;
+        roster.insert(normalize_member_number(&member.member_number), member);
+    }
+    Ok(roster)
+}
+
+/// Check every driver at the event against the roster, marking those who are not eligible members as non-members.
+/// Expiry is checked against `as_of`, an ISO or US date; without one, expired memberships are not detected.
+pub fn check_memberships(
+    results: &mut EventResults,
+    roster: &HashMap<String, RosterMember>,
+    as_of: Option<&str>,
+    rules: &MembershipRules,
+) -> Result<Vec<MembershipCheck>, String> {
+    let as_of = as_of
+        .map(|date| iso_date(date).ok_or_else(|| format!("Event date '{date}' is not a date")))
+        .transpose()?;
+    let as_of = as_of.as_deref();
+    let mut checks = results
+        .results
+        .values_mut()
+        .flat_map(|class_results| class_results.drivers.iter_mut())
+        .map(|driver| {
+            let check = check_driver(driver, roster, as_of, rules);
+            driver.non_member = !check.eligible;
+            check
+        })
+        .collect::<Vec<MembershipCheck>>();
+    checks.sort_by(|lhs, rhs| lhs.id.cmp(&rhs.id));
+    Ok(checks)
+}
+
+fn check_driver(
+    driver: &Driver,
+    roster: &HashMap<String, RosterMember>,
+    as_of: Option<&str>,
+    rules: &MembershipRules,
+) -> MembershipCheck {
+    let member = roster.get(&normalize_member_number(&driver.member_number));
+    let status = if driver.member_number.is_empty() {
+        MembershipStatus::No_Number
+    } else if !is_valid_member_number(&driver.member_number) {
+        MembershipStatus::Invalid_Number
+    } else {
+        match member {
+            None => MembershipStatus::Not_On_Roster,
+            Some(member) if member.is_weekend() => MembershipStatus::Weekend,
+            Some(member) => match as_of {
+                Some(as_of) if member.expires.as_str() < as_of => MembershipStatus::Expired,
+                _ => MembershipStatus::Member,
+            },
+        }
+    };
+    MembershipCheck {
+        id: driver.id.clone(),
+        driver: driver.name.clone(),
+        member_number: driver.member_number.clone(),
+        status,
+        roster_name: member.map(|m| m.name.clone()),
+        expires: member.map(|m| m.expires.clone()),
+        name_mismatch: member.is_some_and(|m| !same_name(&m.name, driver)),
+        eligible: rules.is_eligible(status),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use crate::enums::membership_status::MembershipStatus;
+    use crate::enums::short_car_class::ShortCarClass;
+    use crate::models::event::Event;
+    use crate::models::membership::MembershipRules;
+    use crate::services::csv::parser::event_results_parser::sample_event_with_msr;
+    use crate::services::tools::membership::{check_memberships, is_valid_member_number, parse_roster};
+
+    // language=CSV
+    const ROSTER: &str = r###""Member #","Name","Expires","Type"
+180831_3,"Fullriede, Rob",12/31/2022,Family
+2,Jeffrey Osborn,2022-01-31,Individual"###;
+
+    #[rstest]
+    #[case("135655", true)]
+    #[case("180831_3", true)]
+    #[case("18083-1", true)]
+    #[case("E123456", true)]
+    #[case("pending", false)]
+    #[case("123 456", false)]
+    #[case("", false)]
+    fn validates_member_numbers(#[case] member_number: &str, #[case] expected: bool) {
+        assert_eq!(is_valid_member_number(member_number), expected);
+    }
+
+    #[test]
+    fn checks_drivers_against_the_roster() {
+        let mut results = sample_event_with_msr(["180831-3", "2", "pending"], ["KC", "STL", "STL"], Event::default());
+
+        let checks = check_memberships(
+            &mut results,
+            &parse_roster(ROSTER.to_string()).unwrap(),
+            Some("4/10/2022"),
+            &MembershipRules::new(false, true),
+        )
+        .unwrap();
+
+        let statuses = checks
+            .iter()
+            .map(|c| (c.driver.as_str(), c.status, c.name_mismatch, c.eligible))
+            .collect::<Vec<(&str, MembershipStatus, bool, bool)>>();
+        assert_eq!(
+            statuses,
+            vec![
+                ("Adam Buffa", MembershipStatus::Invalid_Number, false, false),
+                ("Jeffrey Osborn", MembershipStatus::Expired, false, false),
+                ("Robert Fullriede", MembershipStatus::Member, true, true),
+            ]
+        );
+        assert_eq!(checks[2].expires, Some("2022-12-31".to_string()));
+
+        let members = results.without(|d| d.non_member);
+        assert_eq!(members.results[&ShortCarClass::AS].drivers.len(), 1);
+        assert!(!members.results.contains_key(&ShortCarClass::BS));
+    }
+
+    #[test]
+    fn rejects_dates_that_cannot_be_compared() {
+        let mut results = sample_event_with_msr(["1", "2", "3"], ["KC", "STL", "STL"], Event::default());
+
+        assert_eq!(
+            check_memberships(
+                &mut results,
+                &parse_roster(ROSTER.to_string()).unwrap(),
+                Some("April 10th"),
+                &MembershipRules::new(false, true),
+            ),
+            Err("Event date 'April 10th' is not a date".to_string())
+        );
+        assert_eq!(
+            parse_roster("\"Member #\",\"Name\",\"Expires\"\n2,Jeffrey Osborn,next year".to_string()),
+            Err("Expiry 'next year' for member 2 is not a date".to_string())
+        );
+    }
+}
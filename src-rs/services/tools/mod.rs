@@ -0,0 +1,7 @@
+pub(crate) mod driver_count;
+pub(crate) mod heat_scheduler;
+pub(crate) mod home_region;
+pub(crate) mod membership;
+pub(crate) mod novice_graduation;
+pub(crate) mod pronto_import;
+pub(crate) mod region_backfill;
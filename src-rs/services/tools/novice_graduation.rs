@@ -0,0 +1,222 @@
+use std::collections::HashMap;
+
+use crate::enums::driver_group::DriverGroup;
+use crate::models::event_results::EventResults;
+use crate::models::novice_graduation::{GraduationRules, NoviceStatus};
+use crate::models::type_aliases::DriverId;
+use crate::services::csv::builder::event::class_results_builder::ClassResultsBuilder;
+use crate::services::csv::builder::event::combined_results_builder::CombinedResultsBuilder;
+use crate::services::reports::event_label;
+
+/// Follow every driver through the season to decide which novices have graduated. Graduation takes effect from the
+/// event after the one that earned it, so the current event only decides whether a graduate is still entered.
+#[derive(Default)]
+pub struct NoviceTracker {
+    class_results_builder: ClassResultsBuilder,
+    combined_results_builder: CombinedResultsBuilder,
+}
+
+impl NoviceTracker {
+    /// Status of every driver entered as a novice at any prior event or the current one, in the order they first
+    /// appeared
+    pub fn track(
+        &self,
+        prior: &[EventResults],
+        current: &EventResults,
+        rules: &GraduationRules,
+    ) -> Result<Vec<NoviceStatus>, String> {
+        let mut statuses: Vec<NoviceStatus> = vec![];
+        let mut ever_novice = vec![];
+
+        for (index, results) in prior.iter().enumerate() {
+            let label = event_label(results, index);
+            let novices = results.get_drivers(Some(DriverGroup::Novice));
+            let novice_trophies = self
+                .combined_results_builder
+                .to_combined_rows(results, DriverGroup::Novice)?
+                .iter()
+                .zip(novices.iter())
+                .filter(|(row, _)| row.trophy)
+                .map(|(_, d)| d.id.clone())
+                .collect::<Vec<DriverId>>();
+            let pax_positions = self
+                .combined_results_builder
+                .to_combined_rows(results, DriverGroup::PAX)?
+                .iter()
+                .zip(results.get_drivers(Some(DriverGroup::PAX)))
+                .map(|(row, d)| (d.id.clone(), row.position))
+                .collect::<HashMap<DriverId, usize>>();
+            ever_novice.extend(novices.iter().map(|d| d.id.clone()));
+
+            for class_results in results.sorted_class_results() {
+                let rows = self.class_results_builder.build_rows(class_results);
+                for (driver, row) in class_results.drivers.iter().zip(rows) {
+                    let status = Self::find_or_insert(&mut statuses, &driver.id, &driver.name);
+                    status.events += 1;
+                    status.class_trophies += row.trophy as usize;
+                    status.novice_trophies += novice_trophies.contains(&driver.id) as usize;
+                    if let Some(position) = pax_positions.get(&driver.id) {
+                        status.best_pax_position =
+                            Some(status.best_pax_position.map_or(*position, |p| p.min(*position)));
+                    }
+                    if status.graduated_after.is_none() {
+                        status.reason = Self::reason(status, rules);
+                        status.graduated_after = status.reason.as_ref().map(|_| label.clone());
+                    }
+                }
+            }
+        }
+
+        for driver in current.get_drivers(Some(DriverGroup::Novice)) {
+            let status = Self::find_or_insert(&mut statuses, &driver.id, &driver.name);
+            status.entered_as_novice = true;
+            status.flagged = status.graduated_after.is_some();
+            ever_novice.push(driver.id.clone());
+        }
+        Ok(statuses
+            .into_iter()
+            .filter(|status| ever_novice.contains(&status.id))
+            .collect())
+    }
+
+    fn find_or_insert<'a>(statuses: &'a mut Vec<NoviceStatus>, id: &DriverId, name: &str) -> &'a mut NoviceStatus {
+        let index = match statuses.iter().position(|s| &s.id == id) {
+            Some(index) => index,
+            None => {
+                statuses.push(NoviceStatus::new(id.clone(), name.to_string()));
+                statuses.len() - 1
+            }
+        };
+        &mut statuses[index]
+    }
+
+    fn reason(status: &NoviceStatus, rules: &GraduationRules) -> Option<String> {
+        if status
+            .best_pax_position
+            .is_some_and(|position| position <= rules.top_pax)
+        {
+            Some(format!("Finished in the top {} on PAX", rules.top_pax))
+        } else if status.events >= rules.max_events {
+            Some(format!("Drove {} events", rules.max_events))
+        } else {
+            None
+        }
+    }
+}
+
+/// Mark the flagged graduates at the current event, clearing the mark from everyone else. Returns the number of
+/// graduates.
+pub fn mark_graduates(results: &mut EventResults, statuses: &[NoviceStatus]) -> usize {
+    results
+        .results
+        .values_mut()
+        .flat_map(|class_results| class_results.drivers.iter_mut())
+        .filter_map(|driver| {
+            driver.graduated = driver.rookie && statuses.iter().any(|s| s.flagged && s.id == driver.id);
+            driver.graduated.then_some(())
+        })
+        .count()
+}
+
+/// Take marked graduates out of the Novice championship. Meant for the championship copy of the results, so they
+/// keep their place in the event's own Novice results.
+pub fn exclude_graduates(results: &mut EventResults) {
+    results
+        .results
+        .values_mut()
+        .flat_map(|class_results| class_results.drivers.iter_mut())
+        .filter(|driver| driver.graduated)
+        .for_each(|driver| driver.rookie = false);
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use crate::enums::class_source::ClassSource;
+    use crate::enums::driver_group::DriverGroup;
+    use crate::models::event::Event;
+    use crate::models::event_results::EventResults;
+    use crate::models::novice_graduation::GraduationRules;
+    use crate::services::csv::parser::event_results_parser::parse;
+    use crate::services::tools::novice_graduation::{exclude_graduates, mark_graduates, NoviceTracker};
+
+    /// 2022 Event 1 with every driver entered as a novice
+    fn event() -> EventResults {
+        let contents = fs::read_to_string("./SampleData/2022/2022_Event1-DavidExport.csv").unwrap();
+        let mut results = parse(None, contents, ClassSource::Pronto, Event::default()).unwrap();
+        results
+            .results
+            .values_mut()
+            .flat_map(|class_results| class_results.drivers.iter_mut())
+            .for_each(|driver| driver.rookie = true);
+        results
+    }
+
+    #[test]
+    fn graduates_on_a_top_pax_finish() {
+        let statuses = NoviceTracker::default()
+            .track(&[event()], &event(), &GraduationRules::new(5, 1, false))
+            .unwrap();
+
+        let fullriede = statuses.iter().find(|s| s.driver == "Robert Fullriede").unwrap();
+        assert_eq!(fullriede.events, 1);
+        assert_eq!(fullriede.class_trophies, 1);
+        assert_eq!(fullriede.novice_trophies, 1);
+        assert_eq!(fullriede.best_pax_position, Some(1));
+        assert_eq!(fullriede.graduated_after, Some("Event #1".to_string()));
+        assert_eq!(fullriede.reason, Some("Finished in the top 1 on PAX".to_string()));
+        assert!(fullriede.flagged);
+        assert!(statuses
+            .iter()
+            .filter(|s| s.driver != "Robert Fullriede")
+            .all(|s| !s.flagged));
+    }
+
+    #[test]
+    fn graduates_after_enough_events() {
+        let statuses = NoviceTracker::default()
+            .track(&[event(), event()], &event(), &GraduationRules::new(2, 0, false))
+            .unwrap();
+
+        assert_eq!(statuses.len(), 3);
+        assert!(statuses.iter().all(|s| s.events == 2 && s.flagged));
+        assert_eq!(statuses[0].graduated_after, Some("Event #2".to_string()));
+        assert_eq!(statuses[0].reason, Some("Drove 2 events".to_string()));
+    }
+
+    #[test]
+    fn excluded_graduates_leave_the_novice_championship() {
+        let mut current = event();
+        let statuses = NoviceTracker::default()
+            .track(&[event()], &current, &GraduationRules::new(5, 1, true))
+            .unwrap();
+
+        assert_eq!(mark_graduates(&mut current, &statuses), 1);
+        let mut championship = current.clone();
+        exclude_graduates(&mut championship);
+
+        let novices = |results: &EventResults| {
+            results
+                .get_drivers(Some(DriverGroup::Novice))
+                .iter()
+                .map(|d| d.name.clone())
+                .collect::<Vec<String>>()
+        };
+        assert_eq!(novices(&championship), vec!["Jeffrey Osborn", "Adam Buffa"]);
+        // The event's own Novice results are unchanged
+        assert_eq!(novices(&current).len(), 3);
+    }
+
+    #[test]
+    fn first_time_novices_have_no_history() {
+        let statuses = NoviceTracker::default()
+            .track(&[], &event(), &GraduationRules::new(5, 1, false))
+            .unwrap();
+
+        assert_eq!(statuses.len(), 3);
+        assert!(statuses
+            .iter()
+            .all(|s| s.events == 0 && s.entered_as_novice && !s.flagged));
+    }
+}
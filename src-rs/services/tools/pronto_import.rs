@@ -0,0 +1,90 @@
+use csv::{QuoteStyle, WriterBuilder};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::services::csv::parser::event_results_parser::parse_msr;
+
+const PRONTO_IMPORT_HEADER: [&str; 9] = [
+    "Last Name",
+    "First Name",
+    "Member #",
+    "Class",
+    "No.",
+    "Vehicle Year/Make/Model/Color",
+    "Modifier/PAX",
+    "Region of Record Abbreviation",
+    "Are you a novice?",
+];
+
+/// Convert an MSR registration export into the column layout Pronto expects for its registration import. Ladies
+/// drivers are marked with an `L` modifier, Pro drivers carry their PAX class as the modifier, and medical
+/// information is dropped.
+#[wasm_bindgen]
+pub fn msr_to_pronto_import(msr_export: String) -> Result<String, String> {
+    let mut drivers = parse_msr(msr_export)?.into_values().collect::<Vec<_>>();
+    drivers.sort_by(|lhs, rhs| {
+        lhs.last_name
+            .to_lowercase()
+            .cmp(&rhs.last_name.to_lowercase())
+            .then(lhs.first_name.to_lowercase().cmp(&rhs.first_name.to_lowercase()))
+    });
+
+    let mut csv = WriterBuilder::new().quote_style(QuoteStyle::Always).from_writer(vec![]);
+    csv.write_record(PRONTO_IMPORT_HEADER).map_err(|e| e.to_string())?;
+
+    for driver in drivers {
+        let (car_class, modifier) = match driver.car_classes() {
+            Some((car_class, pax_class)) => (
+                car_class.name().to_string(),
+                pax_class.map(|c| c.name().to_string()).unwrap_or_default(),
+            ),
+            None => (driver.class_and_pax.trim().to_string(), "".to_string()),
+        };
+        let modifier = if driver.ladies.unwrap_or_default() != 0 {
+            "L".to_string()
+        } else {
+            modifier
+        };
+
+        csv.write_record(vec![
+            driver.last_name.clone(),
+            driver.first_name.clone(),
+            driver.member_number.clone(),
+            car_class,
+            format!("{}", driver.car_number),
+            driver.car.clone(),
+            modifier,
+            driver.region.clone().unwrap_or_default(),
+            if driver.novice.unwrap_or_default() != 0 {
+                "1".to_string()
+            } else {
+                "0".to_string()
+            },
+        ])
+        .map_err(|e| e.to_string())?;
+    }
+
+    String::from_utf8(csv.into_inner().map_err(|e| e.to_string())?).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::services::tools::pronto_import::msr_to_pronto_import;
+
+    // language=CSV
+    const MSR_DATA: &str = r###""Last Name","First Name","Member #","Class + Modifier/PAX","No.","Vehicle Year/Make/Model/Color","Region of Record Abbreviation","Medical condition? (Optional)","Is a pro","Novice","Ladies"
+"Albin","Andrea","180831_3","BS","41","2024 Honda Civic Type R/BLUE","SILR","No","","0","1"
+"Adams","Conrad","667604","ES","24","1999 Mazda Miata/Green","CILL","No","","1","0"
+"Whitworth","Dave","271749_1","PSS","139","2012 Ford Mustang/Black","NEB","","1","0","0""###;
+
+    #[test]
+    fn converts_msr_export() {
+        assert_eq!(
+            msr_to_pronto_import(MSR_DATA.to_string()).unwrap(),
+            "\"Last Name\",\"First Name\",\"Member #\",\"Class\",\"No.\",\"Vehicle Year/Make/Model/Color\",\"Modifier/PAX\",\"Region of Record Abbreviation\",\"Are you a novice?\"
+\"Adams\",\"Conrad\",\"667604\",\"ES\",\"24\",\"1999 Mazda Miata/Green\",\"\",\"CILL\",\"1\"
+\"Albin\",\"Andrea\",\"180831_3\",\"BS\",\"41\",\"2024 Honda Civic Type R/BLUE\",\"L\",\"SILR\",\"0\"
+\"Whitworth\",\"Dave\",\"271749_1\",\"P\",\"139\",\"2012 Ford Mustang/Black\",\"SS\",\"NEB\",\"0\"
+"
+        );
+    }
+}
@@ -0,0 +1,53 @@
+use std::collections::HashMap;
+
+use crate::models::event_results::EventResults;
+use crate::models::msr_driver::MsrDriver;
+use crate::models::type_aliases::DriverId;
+
+/// Fill in the region of record for every driver (including no-shows) that doesn't have one, using the MSR
+/// registration with the same driver ID. Returns the number of drivers that were updated.
+pub fn backfill_regions(results: &mut EventResults, msr_drivers: &HashMap<DriverId, MsrDriver>) -> usize {
+    results
+        .results
+        .values_mut()
+        .flat_map(|class_results| class_results.drivers.iter_mut())
+        .chain(results.no_shows.iter_mut())
+        .filter(|driver| driver.region.trim().is_empty())
+        .filter_map(|driver| {
+            msr_drivers
+                .get(&driver.id)
+                .and_then(|msr_driver| msr_driver.region.clone())
+                .filter(|region| !region.trim().is_empty())
+                .map(|region| driver.region = region.trim().to_string())
+        })
+        .count()
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use crate::enums::class_source::ClassSource;
+    use crate::enums::short_car_class::ShortCarClass;
+    use crate::models::event::Event;
+    use crate::services::csv::parser::event_results_parser::{parse, parse_msr};
+    use crate::services::tools::region_backfill::backfill_regions;
+
+    // language=CSV
+    const MSR_DATA: &str = r###""Last Name","First Name","Member #","Class + Modifier/PAX","No.","Vehicle Year/Make/Model/Color","Region of Record Abbreviation","Medical condition? (Optional)","Is a pro","Novice","Ladies"
+Fullriede,Robert,1,AS,1,Vette,STL,,0,0,0
+Osborn,Jeffrey,2,AS,2,Foobar,,,0,0,0"###;
+
+    #[test]
+    fn backfills_missing_regions() {
+        let sample_contents = fs::read_to_string("./SampleData/2022/2022_Event1-DavidExport.csv").unwrap();
+        let mut results = parse(None, sample_contents, ClassSource::Pronto, Event::default()).unwrap();
+
+        let updated = backfill_regions(&mut results, &parse_msr(MSR_DATA.to_string()).unwrap());
+
+        assert_eq!(updated, 1);
+        let a_street = results.results.get(&ShortCarClass::AS).unwrap();
+        assert_eq!(a_street.drivers[0].region, "STL");
+        assert_eq!(a_street.drivers[1].region, "");
+    }
+}
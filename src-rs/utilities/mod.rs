@@ -1,11 +1,20 @@
+#[cfg(target_arch = "wasm32")]
 use wasm_bindgen::prelude::wasm_bindgen;
 
+#[cfg(target_arch = "wasm32")]
 #[wasm_bindgen]
 extern "C" {
     #[wasm_bindgen(js_namespace = console)]
     pub fn log(s: &str);
 }
 
+/// Outside the browser there is no console to forward to, so messages go to stderr, leaving stdout to the engine's
+/// output
+#[cfg(not(target_arch = "wasm32"))]
+pub fn log(s: &str) {
+    eprintln!("{s}");
+}
+
 #[macro_export]
 macro_rules! console_log {
     ($($t:tt)*) => ($crate::utilities::log(&format_args!($($t)*).to_string()))
@@ -90,7 +90,7 @@ export default function App(): JSX.Element {
         <Row>
           <Col>
             <FileUploadBox
-              label={'MotorsportReg Export'}
+              label={'MotorsportReg Export (optional)'}
               file={msrExportFile}
               accept={'.csv'}
               onFileSelect={async (f) => {
@@ -105,14 +105,13 @@ export default function App(): JSX.Element {
             />
 
             <FileUploadBox
-              disabled={!msrExportFile}
               label={'Full Event Results (by class)'}
               file={eventResultsFile}
               accept={'.csv'}
               onFileSelect={async (f) => {
                 try {
                   const rusty = new SccaSoloPointsEngine(
-                    await msrExportFile!.text(),
+                    msrExportFile ? await msrExportFile.text() : undefined,
                     await f.text(),
                   );
                   const driversInError =
@@ -28,6 +28,12 @@ function prettyNameBuilder(carClass: MangledCarClass): string {
   return `${carClass.short} - ${to_display_name(LongCarClass[carClass.long])}`;
 }
 
+// Event CSVs open with the event's details and a blank line when the event was described
+function withoutEventDetails(csv: string): string {
+  const tableStart = csv.indexOf('\n\n');
+  return tableStart === -1 ? csv : csv.slice(tableStart + 2);
+}
+
 export function EventResults({
   pointsEngine,
 }: EventResultsProps): JSX.Element | null {
@@ -53,15 +59,7 @@ export function EventResults({
               icon={faDownload}
               onClick={() => {
                 setExportFilename('event_class_results.csv');
-                setCsvContent(
-                  [
-                    `${header}\n`,
-                    ...classResults.map(
-                      ([carClass, csv]) =>
-                        `${prettyNameBuilder(carClass)}\n${csv}`,
-                    ),
-                  ].join(''),
-                );
+                setCsvContent(pointsEngine.get_event_class_results_csv());
               }}
             />
           </Button>
@@ -132,7 +130,7 @@ export function EventResults({
           <Accordion.Collapse eventKey={DriverGroup[driverGroup]}>
             <Card.Body>
               <CsvTable
-                csv={csvContent}
+                csv={withoutEventDetails(csvContent)}
                 keyBuilder={(driver) =>
                   `${DriverGroup[driverGroup]} - ${driver[2]}`
                 }